@@ -0,0 +1,44 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use fraction_calculator::fraction::Fraction;
+
+// The first pair stays on the i64 fast path, the second overflows an i64 intermediate and is
+// redone in i128 but still reduces to a result that fits
+const INPUTS: [(&str, Fraction, Fraction); 2] = [
+    ("small", Fraction::new(355, 113), Fraction::new(-22, 7)),
+    ("promoted", Fraction::new(i64::MAX, 6), Fraction::new(i64::MAX - 1, 4)),
+];
+
+fn bench_arithmetic(c: &mut Criterion) {
+    let mut group = c.benchmark_group("arithmetic");
+
+    for (label, a, b) in INPUTS.iter() {
+        let operands = (*a, *b);
+        group.bench_with_input(BenchmarkId::new("checked_add", label), &operands, |bencher, &(a, b)| {
+            bencher.iter(|| black_box(a).checked_add(black_box(b)))
+        });
+        group.bench_with_input(BenchmarkId::new("checked_mul", label), &operands, |bencher, &(a, b)| {
+            bencher.iter(|| black_box(a).checked_mul(black_box(b)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_i128_baseline(c: &mut Criterion) {
+    let mut group = c.benchmark_group("arithmetic_i128_baseline");
+
+    for (label, a, b) in INPUTS.iter() {
+        let operands = (*a, *b);
+        group.bench_with_input(BenchmarkId::new("checked_add_wide", label), &operands, |bencher, &(a, b)| {
+            bencher.iter(|| black_box(a).checked_add_wide(black_box(b)))
+        });
+        group.bench_with_input(BenchmarkId::new("checked_mul_wide", label), &operands, |bencher, &(a, b)| {
+            bencher.iter(|| black_box(a).checked_mul_wide(black_box(b)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_arithmetic, bench_i128_baseline);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use fraction_calculator::math;
+
+// The repeated subtraction GCD the binary GCD replaced, as the baseline to measure it against
+fn gcd_by_subtraction(a: i64, b: i64) -> i64 {
+    let mut a = a.abs();
+    let mut b = b.abs();
+
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+
+    while a != b {
+        if a > b {
+            a -= b;
+        } else {
+            b -= a;
+        }
+    }
+    a
+}
+
+// Pairs where one value is much larger than the other are the worst case for the subtraction GCD
+const INPUTS: [(i64, i64); 4] = [
+    (1, 100_000),
+    (3, 1_000_003),
+    (832_040, 514_229),
+    (1 << 30, 1 << 20),
+];
+
+fn bench_gcd(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gcd");
+
+    for (a, b) in INPUTS.iter() {
+        let label = format!("{}, {}", a, b);
+        group.bench_with_input(BenchmarkId::new("binary", &label), &(*a, *b), |bencher, &(a, b)| {
+            bencher.iter(|| math::gcd(black_box(a), black_box(b)))
+        });
+        group.bench_with_input(BenchmarkId::new("subtraction", &label), &(*a, *b), |bencher, &(a, b)| {
+            bencher.iter(|| gcd_by_subtraction(black_box(a), black_box(b)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_gcd);
+criterion_main!(benches);
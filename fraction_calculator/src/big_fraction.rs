@@ -0,0 +1,372 @@
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::str::FromStr;
+use core::ops::{Add, Sub, Neg, Mul, Div};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+use crate::fraction::{DivisionByZero, ParseFractionError};
+use crate::parse::{split_decimal, split_fraction, split_mixed, split_percent, Decimal};
+
+/// Arbitrary precision counterpart to `Fraction`, enabled with the `big` feature
+///
+/// Results of arithmetic are always reduced so the numerator and denominator only grow as much as
+/// the value requires. Like `Fraction`, the denominator can never be zero.
+#[derive(Debug, Clone)]
+pub struct BigFraction {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl BigFraction {
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        Self::from_bigints(BigInt::from(numerator), BigInt::from(denominator))
+    }
+
+    /// Panics if the denominator is zero
+    pub fn from_bigints(numerator: BigInt, denominator: BigInt) -> Self {
+        if denominator.is_zero() {
+            panic!("attempt to create a fraction with a zero denominator");
+        }
+
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    pub fn simplify(&mut self) {
+        let gcd = self.numerator.gcd(&self.denominator);
+        if !gcd.is_one() {
+            self.numerator /= &gcd;
+            self.denominator /= &gcd;
+        }
+
+        if self.denominator.is_negative() {
+            self.numerator = -&self.numerator;
+            self.denominator = -&self.denominator;
+        }
+    }
+
+    pub fn reciprocal(&self) -> Result<Self, DivisionByZero> {
+        if self.is_zero() {
+            return Err(DivisionByZero);
+        }
+        Ok(Self::from_bigints(self.denominator.clone(), self.numerator.clone()))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    /// Never returns `None`, it only exists so the evaluator can use either backend the same way
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    /// Never returns `None`, it only exists so the evaluator can use either backend the same way
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(self - other)
+    }
+
+    /// Never returns `None`, it only exists so the evaluator can use either backend the same way
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    /// Only returns `None` if `other` is zero since the result can't overflow
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        Some(self * other.reciprocal().ok()?)
+    }
+
+    // Same construction as `Fraction::from_decimal`, which can't overflow here
+    fn from_decimal(decimal: &Decimal) -> Self {
+        let digits = |s: &str| if s.is_empty() { BigInt::zero() } else { parse_digits(s) };
+        let scale = BigInt::from(10).pow(decimal.fractional.len() as u32);
+        let nines: BigInt = BigInt::from(10).pow(decimal.repeating.len() as u32) - 1;
+        let (numerator, denominator) = if nines.is_zero() {
+            (digits(decimal.fractional), scale)
+        } else {
+            (digits(decimal.fractional) * &nines + digits(decimal.repeating), scale * nines)
+        };
+        let magnitude = digits(decimal.whole) * &denominator + numerator;
+        BigFraction::reduced(if decimal.negative { -magnitude } else { magnitude }, denominator)
+    }
+
+    fn reduced(numerator: BigInt, denominator: BigInt) -> Self {
+        let mut f = Self::from_bigints(numerator, denominator);
+        f.simplify();
+        f
+    }
+}
+
+impl Display for BigFraction {
+    /// Written without simplifying but with the sign moved onto the numerator, like `Fraction`
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let sign = if self.numerator.is_negative() != self.denominator.is_negative() && !self.is_zero() {
+            "-"
+        } else {
+            ""
+        };
+        let (numerator, denominator) = (self.numerator.magnitude(), self.denominator.magnitude());
+        if denominator.is_one() {
+            write!(f, "{}{}", sign, numerator)
+        } else {
+            write!(f, "{}{}/{}", sign, numerator, denominator)
+        }
+    }
+}
+
+impl FromStr for BigFraction {
+    type Err = ParseFractionError;
+
+    /// Accepts the same integers, fractions, mixed numbers, decimals and percents as `Fraction`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(number) = split_percent(s)? {
+            return Ok(BigFraction::from_str(number)? * BigFraction::new(1, 100));
+        }
+
+        if let Some(mixed) = split_mixed(s)? {
+            let denominator = parse_digits(mixed.denominator);
+            if denominator.is_zero() {
+                return Err(ParseFractionError::ZeroDenominator);
+            }
+
+            let magnitude = parse_digits(mixed.whole) * &denominator + parse_digits(mixed.numerator);
+            let numerator = if mixed.negative { -magnitude } else { magnitude };
+            return Ok(BigFraction::reduced(numerator, denominator));
+        }
+
+        if let Some(decimal) = split_decimal(s)? {
+            return Ok(BigFraction::from_decimal(&decimal));
+        }
+
+        match split_fraction(s)? {
+            (numerator, Some(denominator)) => {
+                let denominator = parse_digits(denominator);
+                if denominator.is_zero() {
+                    return Err(ParseFractionError::ZeroDenominator);
+                }
+                Ok(BigFraction::from_bigints(parse_digits(numerator), denominator))
+            }
+            (num, None) => Ok(BigFraction::from(parse_digits(num))),
+        }
+    }
+}
+
+// The parse module has already checked these are digits, which always fit in a BigInt
+fn parse_digits(digits: &str) -> BigInt {
+    digits.parse().expect("digits should always parse into a BigInt")
+}
+
+impl From<BigInt> for BigFraction {
+    fn from(num: BigInt) -> Self {
+        BigFraction::from_bigints(num, BigInt::one())
+    }
+}
+
+impl From<i32> for BigFraction {
+    fn from(num: i32) -> Self {
+        BigFraction::from(BigInt::from(num))
+    }
+}
+
+impl Add for BigFraction {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        if self.denominator == other.denominator {
+            BigFraction::reduced(self.numerator + other.numerator, self.denominator)
+        } else {
+            BigFraction::reduced(
+                self.numerator * &other.denominator + other.numerator * &self.denominator,
+                self.denominator * other.denominator,
+            )
+        }
+    }
+}
+
+impl Sub for BigFraction {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self + -other
+    }
+}
+
+impl Neg for BigFraction {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        BigFraction::from_bigints(-self.numerator, self.denominator)
+    }
+}
+
+impl Mul for BigFraction {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        BigFraction::reduced(
+            self.numerator * other.numerator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Div for BigFraction {
+    type Output = Self;
+
+    /// Panics on division by zero, use `checked_div` to handle it
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self::Output {
+        match other.reciprocal() {
+            Ok(reciprocal) => self * reciprocal,
+            Err(_) => panic!("attempt to divide a fraction by zero"),
+        }
+    }
+}
+
+impl PartialEq for BigFraction {
+    fn eq(&self, other: &Self) -> bool {
+        &self.numerator * &other.denominator == &other.numerator * &self.denominator
+    }
+}
+
+impl PartialEq<i32> for BigFraction {
+    fn eq(&self, other: &i32) -> bool {
+        self.numerator == &self.denominator * *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::big_fraction::BigFraction;
+    use crate::fraction::{DivisionByZero, ParseFractionError};
+    use num_bigint::BigInt;
+    use std::str::FromStr;
+
+    fn primes(count: usize) -> Vec<i64> {
+        let mut primes = Vec::new();
+        let mut candidate = 2;
+        while primes.len() < count {
+            if primes.iter().all(|p| candidate % p != 0) {
+                primes.push(candidate);
+            }
+            candidate += 1;
+        }
+        primes
+    }
+
+    #[test]
+    fn product_of_fifty_prime_fractions() {
+        let primes = primes(100);
+        let (numerators, denominators) = primes.split_at(50);
+
+        let mut result = BigFraction::from(1);
+        for (n, d) in numerators.iter().zip(denominators) {
+            result = result * BigFraction::new(*n, *d);
+        }
+
+        let numerator: BigInt = numerators.iter().map(|&n| BigInt::from(n)).product();
+        let denominator: BigInt = denominators.iter().map(|&d| BigInt::from(d)).product();
+        assert_eq!(result.numerator, numerator);
+        assert_eq!(result.denominator, denominator);
+        assert_eq!(result, BigFraction::from_bigints(numerator, denominator));
+    }
+
+    #[test]
+    fn addition() {
+        let result = BigFraction::new(1, 3) + BigFraction::new(1, 6);
+        assert_eq!(result, BigFraction::new(1, 2));
+    }
+
+    #[test]
+    fn subtraction_to_negative() {
+        let result = BigFraction::new(1, 3) - BigFraction::new(1, 2);
+        assert_eq!(result.to_string(), "-1/6");
+    }
+
+    #[test]
+    fn division() {
+        let result = BigFraction::new(1, 6) / BigFraction::new(1, 3);
+        assert_eq!(result, BigFraction::new(1, 2));
+    }
+
+    #[test]
+    fn from_string_beyond_i64() {
+        let result = BigFraction::from_str("100000000000000000000/3").unwrap();
+        let expected = BigFraction::new(10_000_000_000, 1) * BigFraction::new(10_000_000_000, 3);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn from_string_zero_denominator() {
+        let result = BigFraction::from_str("1/0");
+        assert_eq!(result.unwrap_err(), ParseFractionError::ZeroDenominator);
+    }
+
+    #[test]
+    fn reciprocal_of_zero() {
+        assert_eq!(BigFraction::new(0, 5).reciprocal().unwrap_err(), DivisionByZero);
+    }
+
+    #[test]
+    fn checked_div_by_zero() {
+        assert_eq!(BigFraction::new(1, 2).checked_div(BigFraction::new(0, 3)), None);
+    }
+
+    #[test]
+    fn simplify_negative_denominator() {
+        let mut f1 = BigFraction::new(4, -8);
+        f1.simplify();
+        assert_eq!(f1.to_string(), "-1/2");
+    }
+
+    #[test]
+    fn display_sign_placements() {
+        assert_eq!(BigFraction::new(1, 2).to_string(), "1/2");
+        assert_eq!(BigFraction::new(-1, 2).to_string(), "-1/2");
+        assert_eq!(BigFraction::new(1, -2).to_string(), "-1/2");
+        assert_eq!(BigFraction::new(-2, -4).to_string(), "2/4");
+        assert_eq!(BigFraction::new(0, -5).to_string(), "0/5");
+        assert_eq!(BigFraction::new(5, -1).to_string(), "-5");
+        let f = BigFraction::new(1, -2);
+        assert_eq!(f.denominator, BigInt::from(-2));
+    }
+
+    #[test]
+    fn from_string_mixed_number() {
+        let result = BigFraction::from_str("-100000000000000000000_1/2").unwrap();
+        assert_eq!(result.to_string(), "-200000000000000000001/2");
+        let result = BigFraction::from_str("1 3");
+        assert_eq!(result.unwrap_err(), ParseFractionError::InvalidMixedNumber { text: "3".to_string() });
+        let result = BigFraction::from_str("1 100000000000000000000/99999999999999999999");
+        let text = "100000000000000000000/99999999999999999999".to_string();
+        assert_eq!(result.unwrap_err(), ParseFractionError::InvalidMixedNumber { text });
+    }
+
+    #[test]
+    fn from_string_decimal() {
+        assert_eq!(BigFraction::from_str("1.2(3)").unwrap().to_string(), "37/30");
+        let result = BigFraction::from_str("-0.1234567890123456789").unwrap();
+        assert_eq!(result.to_string(), "-1234567890123456789/10000000000000000000");
+    }
+
+    #[test]
+    fn from_string_percent() {
+        assert_eq!(BigFraction::from_str("-12.5%").unwrap().to_string(), "-1/8");
+        let expected = ParseFractionError::InvalidNumerator { text: "%12".to_string() };
+        assert_eq!(BigFraction::from_str("%12").unwrap_err(), expected);
+    }
+
+    #[test]
+    fn from_string_errors() {
+        let result = BigFraction::from_str("+3/4").unwrap();
+        assert_eq!(result, BigFraction::new(3, 4));
+        let result = BigFraction::from_str("3/-4");
+        assert_eq!(result.unwrap_err(), ParseFractionError::InvalidDenominator { text: "-4".to_string() });
+        let result = BigFraction::from_str("3a/4");
+        assert_eq!(result.unwrap_err(), ParseFractionError::InvalidNumerator { text: "3a".to_string() });
+        let result = BigFraction::from_str("3/4x");
+        assert_eq!(result.unwrap_err(), ParseFractionError::TrailingGarbage { text: "x".to_string() });
+    }
+}
@@ -1,31 +1,55 @@
-use super::Fraction;
-use std::str::FromStr;
-use std::fmt::{Result as FmtResult, Formatter, Display};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+use crate::fraction::ParseFractionError;
+use core::fmt::{Result as FmtResult, Formatter, Display};
 
 // https://en.wikipedia.org/wiki/Shunting-yard_algorithm
 // https://en.wikipedia.org/wiki/Reverse_Polish_notation
 
-// TODO: Make error more descriptive
+// The calculator switches to arbitrary precision fractions when the `big` feature is enabled
+#[cfg(not(feature = "big"))]
+pub use crate::fraction::Fraction as Value;
+#[cfg(feature = "big")]
+pub use crate::big_fraction::BigFraction as Value;
+
 #[derive(Debug)]
 pub enum EquationError {
-    UnableToTokenize,
+    UnableToTokenize { token: String, error: ParseFractionError },
     UnableToConvertToPostfix,
     UnableToEvaluate,
+    Overflow,
+    DivisionByZero,
 }
 
 impl EquationError {
     fn message(&self) -> &str {
         match self {
-            Self::UnableToTokenize => "Unable to tokenize the equation",
+            Self::UnableToTokenize { .. } => "Unable to tokenize the equation",
             Self::UnableToConvertToPostfix => "Unable to convert to postfix notation",
             Self::UnableToEvaluate => "Unable to evaluate the postfix equation",
+            Self::Overflow => "The result is too large to represent (overflow)",
+            Self::DivisionByZero => "Division by zero",
         }
     }
 }
 
 impl Display for EquationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "Error: {}", self.message())
+        match self {
+            Self::UnableToTokenize { token, error } => write!(f, "Error: could not parse '{}': {}", token, error),
+            _ => write!(f, "Error: {}", self.message()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EquationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::UnableToTokenize { error, .. } => Some(error),
+            _ => None,
+        }
     }
 }
 
@@ -63,9 +87,9 @@ impl Display for OperatorType {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 enum Token {
-    Number(Fraction),
+    Number(Value),
     Operator(OperatorType),
 }
 
@@ -78,12 +102,13 @@ impl Display for Token {
     }
 }
 
-/// Only supports integers, fractions, +, -, *, /
+/// Only supports integers, fractions, decimals, percents, mixed numbers written with an underscore
+/// like 1_3/4, +, -, *, /
 /// Does not support parenthesis, functions, or other operators
 pub struct Equation;
 
 impl Equation {
-    pub fn eval(input: &str) -> Result<Fraction, EquationError> {
+    pub fn eval(input: &str) -> Result<Value, EquationError> {
         let tokens = Self::tokenize(input)?;
         let rpn = Self::shunting_yard_algorithm(&tokens)?;
         let result = Self::evaluate_rpn(&rpn)?;
@@ -99,10 +124,13 @@ impl Equation {
                 "*" => tokens.push(Token::Operator(OperatorType::Mul)),
                 "/" => tokens.push(Token::Operator(OperatorType::Div)),
                 _ => {
-                    if let Ok(fraction) = Fraction::from_str(token) {
-                        tokens.push(Token::Number(fraction));
-                    } else {
-                        return Err(EquationError::UnableToTokenize);
+                    match Value::from_str(token) {
+                        Ok(fraction) => tokens.push(Token::Number(fraction)),
+                        Err(ParseFractionError::ZeroDenominator) => return Err(EquationError::DivisionByZero),
+                        Err(ParseFractionError::Overflow) => return Err(EquationError::Overflow),
+                        Err(error) => {
+                            return Err(EquationError::UnableToTokenize { token: token.to_string(), error })
+                        }
                     }
                 }
             };
@@ -116,7 +144,7 @@ impl Equation {
 
         for token in tokens {
             match token {
-                Token::Number(_) => output_queue.push(*token),
+                Token::Number(_) => output_queue.push(token.clone()),
                 Token::Operator(current_operator) => {
                     while let Some(Token::Operator(top_operator)) = operator_stack.last() {
                         if current_operator.precedence() > top_operator.precedence() {
@@ -131,13 +159,13 @@ impl Equation {
                             return Err(EquationError::UnableToConvertToPostfix);
                         }
                     }
-                    operator_stack.push(*token); // Always push the current_operator onto the stack
+                    operator_stack.push(token.clone()); // Always push the current_operator onto the stack
                 },
             }
         }
 
         // At this point the operator_stack should be sorted by highest precedence to lowest
-        while operator_stack.len() > 0 {
+        while !operator_stack.is_empty() {
             if let Some(top_token) = operator_stack.pop() {
                 output_queue.push(top_token);
             }
@@ -146,22 +174,28 @@ impl Equation {
         Ok(output_queue)
     }
 
-    fn evaluate_rpn(postfix: &Vec<Token>) -> Result<Fraction, EquationError>{
+    fn evaluate_rpn(postfix: &Vec<Token>) -> Result<Value, EquationError>{
         let mut number_stack: Vec<Token> = Vec::new();
 
         for token in postfix {
             match token {
-                Token::Number(_) => number_stack.push(*token),
+                Token::Number(_) => number_stack.push(token.clone()),
                 Token::Operator(operator) => {
                     if let Some(Token::Number(num2)) = number_stack.pop() {
                         if let Some(Token::Number(num1)) = number_stack.pop() {
-                            let result: Fraction = match operator {
-                                OperatorType::Add => num1 + num2,
-                                OperatorType::Sub => num1 - num2,
-                                OperatorType::Mul => num1 * num2,
-                                OperatorType::Div => num1 / num2,
+                            let result = match operator {
+                                OperatorType::Add => num1.checked_add(num2),
+                                OperatorType::Sub => num1.checked_sub(num2),
+                                OperatorType::Mul => num1.checked_mul(num2),
+                                OperatorType::Div => match num2.reciprocal() {
+                                    Ok(reciprocal) => num1.checked_mul(reciprocal),
+                                    Err(_) => return Err(EquationError::DivisionByZero),
+                                },
                             };
-                            number_stack.push(Token::Number(result));
+                            match result {
+                                Some(result) => number_stack.push(Token::Number(result)),
+                                None => return Err(EquationError::Overflow),
+                            }
                         } else {
                             return Err(EquationError::UnableToEvaluate);
                         }
@@ -182,16 +216,17 @@ impl Equation {
 
 #[cfg(test)]
 mod tests {
-    use crate::equation::{Equation, Token, OperatorType};
-    use super::Fraction;
+    use crate::equation::{Equation, EquationError, Token, OperatorType};
+    use crate::fraction::ParseFractionError;
+    use super::Value;
     use itertools::Itertools;
 
     #[test]
     fn rpn1() {
         let tokens = vec![
-            Token::Number(Fraction::new(3, 1)),
+            Token::Number(Value::new(3, 1)),
             Token::Operator(OperatorType::Add),
-            Token::Number(Fraction::new(4, 1)),
+            Token::Number(Value::new(4, 1)),
         ];
         let result = Equation::shunting_yard_algorithm(&tokens).unwrap().iter().join(" ");
         let expected = "3 4 +";
@@ -201,11 +236,11 @@ mod tests {
     #[test]
     fn rpn2() {
         let tokens = vec![
-            Token::Number(Fraction::new(2, 3)),
+            Token::Number(Value::new(2, 3)),
             Token::Operator(OperatorType::Add),
-            Token::Number(Fraction::new(5, 8)),
+            Token::Number(Value::new(5, 8)),
             Token::Operator(OperatorType::Mul),
-            Token::Number(Fraction::new(-8, 7)),
+            Token::Number(Value::new(-8, 7)),
         ];
         let result = Equation::shunting_yard_algorithm(&tokens).unwrap().iter().join(" ");
         let expected = "2/3 5/8 -8/7 * +";
@@ -222,7 +257,90 @@ mod tests {
     #[test]
     fn eval2() {
         let result = Equation::eval("1/2 + 2 * -1/8").unwrap();
-        let expected = Fraction::new(1, 4);
+        let expected = Value::new(1, 4);
         assert_eq!(result, expected)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn eval_zeros() {
+        let mut result = Equation::eval("0/4 + 0/4").unwrap();
+        result.simplify();
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    #[cfg(not(feature = "big"))]
+    fn eval_overflow() {
+        let result = Equation::eval("9223372036854775807/2 + 9223372036854775807/3");
+        assert!(matches!(result, Err(EquationError::Overflow)));
+    }
+
+    #[test]
+    fn eval_beyond_i32() {
+        let result = Equation::eval("100000 * 100000 / 3").unwrap();
+        let expected = Value::new(10_000_000_000, 3);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn eval_division_by_zero() {
+        let result = Equation::eval("1 / 0");
+        assert!(matches!(result, Err(EquationError::DivisionByZero)));
+
+        let result = Equation::eval("3/4 / 0/7");
+        assert!(matches!(result, Err(EquationError::DivisionByZero)));
+    }
+
+    #[test]
+    fn eval_zero_denominator() {
+        let result = Equation::eval("1/0 + 1");
+        assert!(matches!(result, Err(EquationError::DivisionByZero)));
+    }
+
+    #[test]
+    fn eval_mixed_numbers() {
+        let result = Equation::eval("1_3/4 + 1/4").unwrap();
+        assert_eq!(result, 2);
+
+        let result = Equation::eval("-2_1/2 * 2").unwrap();
+        assert_eq!(result, -5);
+    }
+
+    #[test]
+    fn eval_invalid_mixed_number() {
+        let result = Equation::eval("1_-3/4 + 1");
+        assert!(matches!(result, Err(EquationError::UnableToTokenize { .. })));
+    }
+
+    #[test]
+    fn eval_decimals() {
+        let result = Equation::eval("0.75 + 0.(3)").unwrap();
+        assert_eq!(result, Value::new(13, 12));
+    }
+
+    #[test]
+    fn eval_percents() {
+        let result = Equation::eval("50% * 3").unwrap();
+        assert_eq!(result, Value::new(3, 2));
+
+        let result = Equation::eval("% * 3");
+        assert!(matches!(result, Err(EquationError::UnableToTokenize { .. })));
+    }
+
+    #[test]
+    fn eval_invalid_number() {
+        let result = Equation::eval("3a/4 + 1");
+        let expected = ParseFractionError::InvalidNumerator { text: "3a".to_string() };
+        assert!(matches!(result, Err(EquationError::UnableToTokenize { ref token, ref error })
+            if token == "3a/4" && *error == expected));
+    }
+
+    #[test]
+    fn eval_error_message() {
+        let error = Equation::eval("1 + 3a/4").unwrap_err();
+        assert_eq!(error.to_string(), "Error: could not parse '3a/4': invalid numerator '3a'");
+
+        let error = Equation::eval("1 +  2").unwrap_err();
+        assert_eq!(error.to_string(), "Error: could not parse '': there is no number");
+    }
+}
@@ -1,417 +1,3648 @@
-use std::fmt::{Display, Debug, Formatter, Result as FmtResult};
-use std::str::FromStr;
-use std::cmp::{Ordering, PartialEq};
-use std::ops::{Add, Sub, Neg, Mul, Div};
+use core::fmt::{Display, Debug, Formatter, Result as FmtResult};
+use core::str::FromStr;
+use core::convert::TryFrom;
+use core::cmp::{Ordering, PartialEq, PartialOrd};
+use core::hash::{Hash, Hasher};
+use core::borrow::Borrow;
+use core::iter::{Sum, Product};
+use core::ops::{Add, Sub, Neg, Mul, Div, Rem, AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::math::{gcd, gcd_wide};
+use crate::parse::{split_decimal, split_fraction, split_mixed, split_percent, Decimal};
 
-#[derive(Debug)]
-pub struct ParseFractionError;
+/// Why a string couldn't be parsed into a fraction, with the part of the string at fault
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseFractionError {
+    EmptyInput,
+    InvalidNumerator { text: String },
+    InvalidDenominator { text: String },
+    InvalidMixedNumber { text: String },
+    InvalidDecimal { text: String },
+    ZeroDenominator,
+    TrailingGarbage { text: String },
+    Overflow,
+}
+
+impl Display for ParseFractionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::EmptyInput => write!(f, "there is no number"),
+            Self::InvalidNumerator { text } => write!(f, "invalid numerator '{}'", text),
+            Self::InvalidDenominator { text } => write!(f, "invalid denominator '{}'", text),
+            Self::InvalidMixedNumber { text } => write!(f, "invalid fractional part '{}' of a mixed number", text),
+            Self::InvalidDecimal { text } => write!(f, "invalid digits '{}' after the decimal point", text),
+            Self::ZeroDenominator => write!(f, "the denominator cannot be zero"),
+            Self::TrailingGarbage { text } => write!(f, "unexpected '{}' after the number", text),
+            Self::Overflow => write!(f, "the number is too large to represent (overflow)"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFractionError {}
+
+impl From<ZeroDenominator> for ParseFractionError {
+    fn from(_: ZeroDenominator) -> Self {
+        ParseFractionError::ZeroDenominator
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FromFloatError {
+    NotFinite,
+    InvalidTolerance,
+    Overflow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZeroDenominator;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DivisionByZero;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FractionOverflow;
+
+impl Display for ZeroDenominator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "The denominator of a fraction cannot be zero")
+    }
+}
+
+impl Display for DivisionByZero {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Division by zero")
+    }
+}
+
+impl Display for FractionOverflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "The result is too large to represent (overflow)")
+    }
+}
+
+impl Display for FromFloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            FromFloatError::NotFinite => write!(f, "Only finite numbers can be converted to a fraction"),
+            FromFloatError::InvalidTolerance => write!(f, "The tolerance must be greater than zero"),
+            FromFloatError::Overflow => write!(f, "The result is too large to represent (overflow)"),
+        }
+    }
+}
+
+/// A fraction can never have a zero denominator, `try_new` returns an error and `new` panics
+///
+/// There is no undefined or NaN-like fraction: 1/0 and 0/0 are rejected when a fraction is
+/// created, parsed, deserialized or divided by zero rather than carried around, so every value
+/// has a single well defined place in the ordering and equality is always reflexive.
+///
+/// Arithmetic results are always reduced. The checked operations, and the operators built on
+/// them, work in i64 first and only redo the calculation in i128 when an intermediate value
+/// overflows, so they fail only if the reduced result itself doesn't fit. That costs a second
+/// attempt in the rare overflowing case in exchange for a much wider range of inputs.
+///
+/// Equality, ordering and hashing all work on the value rather than the stored numerator and
+/// denominator, so 1/2, 2/4 and -3/-6 are interchangeable as `HashMap` keys.
+#[derive(Debug, Clone, Copy)]
+pub struct Fraction {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Fraction {
+    pub const ZERO: Fraction = Fraction::from_int(0);
+    pub const ONE: Fraction = Fraction::from_int(1);
+
+    /// Panics if the denominator is zero, use `try_new` to handle it
+    pub const fn new(numerator: i64, denominator: i64) -> Self {
+        match Fraction::try_new(numerator, denominator) {
+            Ok(f) => f,
+            Err(_) => panic!("attempt to create a fraction with a zero denominator"),
+        }
+    }
+
+    pub const fn try_new(numerator: i64, denominator: i64) -> Result<Self, ZeroDenominator> {
+        if denominator == 0 {
+            return Err(ZeroDenominator);
+        }
+
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Takes an i64 rather than an i32 so any whole number that fits in a fraction can be used
+    pub const fn from_int(num: i64) -> Self {
+        Self {
+            numerator: num,
+            denominator: 1,
+        }
+    }
+
+    /// Same as `new`, as the counterpart to `into_parts`
+    pub const fn from_parts(numerator: i64, denominator: i64) -> Self {
+        Fraction::new(numerator, denominator)
+    }
+
+    /// The numerator as stored, which is not simplified and can be negative even when the
+    /// fraction is positive, e.g. `Fraction::new(-2, -4).numerator()` is -2. Call
+    /// `clone_simplified` first for the lowest terms with the sign on the numerator.
+    pub const fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// The denominator as stored, which is never zero but can be negative, see `numerator`
+    pub const fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    /// The stored numerator and denominator, exactly as `numerator` and `denominator` return them
+    pub const fn into_parts(self) -> (i64, i64) {
+        (self.numerator, self.denominator)
+    }
+
+    /// Panics if the simplified fraction doesn't fit, see `simplify`
+    pub fn clone_simplified(&self) -> Self {
+        let mut f = *self;
+        f.simplify();
+        f
+    }
+
+    /// Lowest terms with a positive denominator, or `None` for i64::MIN / -1 since its
+    /// simplified form 2^63 doesn't fit in an i64
+    pub fn checked_simplified(&self) -> Option<Self> {
+        let (numerator, denominator) = self.widen();
+        Fraction::narrow(numerator, denominator)
+    }
+
+    /// Reduces to lowest terms with a positive denominator. Panics for i64::MIN / -1, whose
+    /// simplified form 2^63 doesn't fit, use `checked_simplified` to handle it.
+    pub fn simplify(&mut self) {
+        *self = self.checked_simplified().expect("attempt to simplify a fraction with overflow");
+    }
+
+    pub fn reciprocal(&self) -> Result<Self, DivisionByZero> {
+        Fraction::try_new(self.denominator, self.numerator).map_err(|_| DivisionByZero)
+    }
+
+    pub fn is_same_as(&self, other: &Self) -> bool {
+        self.numerator == other.numerator && self.denominator == other.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        // wrapping_rem only differs from % for i64::MIN % -1, which overflows but divides evenly
+        self.numerator.wrapping_rem(self.denominator) == 0
+    }
+
+    /// True if the magnitude of the fraction is less than one
+    pub fn is_proper(&self) -> bool {
+        self.numerator.unsigned_abs() < self.denominator.unsigned_abs()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.signum() < 0
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.signum() > 0
+    }
+
+    /// Returns -1, 0 or 1 no matter if the sign is on the numerator, denominator, or both
+    pub fn signum(&self) -> i32 {
+        (self.numerator.signum() * self.denominator.signum()) as i32
+    }
+
+    /// Makes both the numerator and denominator positive without simplifying, panics if one of
+    /// them is i64::MIN and the fraction can't be simplified enough to fit, use `checked_abs` to
+    /// handle it
+    pub fn abs(&self) -> Self {
+        self.checked_abs().expect("attempt to take the absolute value of a fraction with overflow")
+    }
+
+    /// Rounds toward negative infinity
+    pub fn floor(&self) -> i64 {
+        let (numerator, denominator) = self.widen_signed_numerator();
+        Fraction::narrow_int(numerator.div_euclid(denominator))
+    }
+
+    /// Rounds toward positive infinity
+    pub fn ceil(&self) -> i64 {
+        let (numerator, denominator) = self.widen_signed_numerator();
+        Fraction::narrow_int(-(-numerator).div_euclid(denominator))
+    }
+
+    /// Rounds toward zero
+    pub fn trunc(&self) -> i64 {
+        let (numerator, denominator) = self.widen_signed_numerator();
+        Fraction::narrow_int(numerator / denominator)
+    }
+
+    /// Rounds to the nearest integer, and away from zero when exactly halfway between two
+    pub fn round(&self) -> i64 {
+        let (numerator, denominator) = self.widen_signed_numerator();
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+        if 2 * remainder.abs() >= denominator {
+            Fraction::narrow_int(quotient + numerator.signum())
+        } else {
+            Fraction::narrow_int(quotient)
+        }
+    }
+
+    /// The nearest multiple of 1/`denominator`, returned over exactly that denominator without
+    /// reducing it, e.g. 5/12 to the nearest sixteenth is 7/16. Ties round away from zero like
+    /// `round`. Panics if the denominator is less than one or the result overflows.
+    pub fn round_to(&self, denominator: i64) -> Fraction {
+        self.snap_to_multiple(denominator, |numerator, denominator| {
+            let quotient = numerator / denominator;
+            if 2 * (numerator % denominator).abs() >= denominator {
+                quotient + numerator.signum()
+            } else {
+                quotient
+            }
+        })
+    }
+
+    /// The largest multiple of 1/`denominator` that isn't above the fraction, see `round_to`
+    pub fn floor_to(&self, denominator: i64) -> Fraction {
+        self.snap_to_multiple(denominator, |numerator, denominator| numerator.div_euclid(denominator))
+    }
+
+    /// The smallest multiple of 1/`denominator` that isn't below the fraction, see `round_to`
+    pub fn ceil_to(&self, denominator: i64) -> Fraction {
+        self.snap_to_multiple(denominator, |numerator, denominator| -(-numerator).div_euclid(denominator))
+    }
+
+    /// The part left over after `trunc`, so it has the same sign as the fraction and
+    /// `x == x.trunc() + x.fract()`
+    pub fn fract(&self) -> Self {
+        let (numerator, denominator) = self.widen_signed_numerator();
+        Fraction::narrow(numerator % denominator, denominator)
+            .expect("attempt to take the fractional part of a fraction with overflow")
+    }
+
+    // The rounding methods can only overflow for i64::MIN / -1
+    fn narrow_int(num: i128) -> i64 {
+        i64::try_from(num).expect("attempt to round a fraction with overflow")
+    }
+
+    pub fn checked_abs(&self) -> Option<Self> {
+        match (self.numerator.checked_abs(), self.denominator.checked_abs()) {
+            (Some(numerator), Some(denominator)) => Some(Self { numerator, denominator }),
+            // i64::MIN has no positive counterpart, but the simplified fraction might fit
+            _ => Fraction::narrow(self.numerator.unsigned_abs() as i128, self.denominator.unsigned_abs() as i128),
+        }
+    }
+
+    /// Negates the numerator, or the denominator when the numerator is i64::MIN, and gives -1
+    /// for i64::MIN / i64::MIN where neither can be. Never actually returns `None` since the sign
+    /// can always go somewhere, it exists to match the other checked operations.
+    pub fn checked_neg(self) -> Option<Self> {
+        if let Some(numerator) = self.numerator.checked_neg() {
+            Fraction::try_new(numerator, self.denominator).ok()
+        } else if let Some(denominator) = self.denominator.checked_neg() {
+            Fraction::try_new(self.numerator, denominator).ok()
+        } else {
+            Some(Fraction::from(-1))
+        }
+    }
+
+    /// Returns `None` if the numerator or denominator of the result would overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.add_small(other, false).or_else(|| self.checked_add_wide(other))
+    }
+
+    /// Returns `None` if the numerator or denominator of the result would overflow
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.add_small(other, true).or_else(|| {
+            let (n1, n2, denominator) = self.over_common_denominator(other);
+            Fraction::narrow(n1.checked_sub(n2)?, denominator)
+        })
+    }
+
+    /// Returns `None` if the numerator or denominator of the result would overflow
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.mul_small(other).or_else(|| self.checked_mul_wide(other))
+    }
+
+    /// Returns `None` if `other` is zero or the numerator or denominator of the result would overflow
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_mul(other.reciprocal().ok()?)
+    }
+
+    /// Remainder of truncated division, `self - (self / other).trunc() * other`, so like the
+    /// integer `%` the result has the sign of `self`. Returns `None` on overflow or if `other` is
+    /// zero.
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        let (numerator, divisor, denominator) = self.over_common_denominator(other);
+        if divisor == 0 {
+            return None;
+        }
+        Fraction::narrow(numerator % divisor, denominator)
+    }
+
+    /// Remainder of floored division by `|other|`, which is never negative. Returns `None` on
+    /// overflow or if `other` is zero.
+    pub fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+        let (numerator, divisor, denominator) = self.over_common_denominator(other);
+        if divisor == 0 {
+            return None;
+        }
+        Fraction::narrow(numerator.rem_euclid(divisor), denominator)
+    }
+
+    /// Panics on overflow or if `other` is zero, use `checked_rem_euclid` to handle them
+    pub fn rem_euclid(self, other: Self) -> Self {
+        if other.is_zero() {
+            panic!("attempt to calculate the remainder of a fraction with a divisor of zero");
+        }
+        self.checked_rem_euclid(other)
+            .expect("attempt to calculate the remainder of fractions with overflow")
+    }
+
+    /// Raises the fraction to an integer power, where anything to the power of zero is one,
+    /// including 0^0. Panics on overflow or when raising zero to a negative power, use
+    /// `checked_pow` to handle them.
+    pub fn pow(self, exp: i32) -> Self {
+        if exp < 0 && self.is_zero() {
+            panic!("attempt to raise zero to a negative power");
+        }
+        self.checked_pow(exp).expect("attempt to raise a fraction to a power with overflow")
+    }
+
+    /// Returns `None` on overflow or when raising zero to a negative power
+    pub fn checked_pow(self, exp: i32) -> Option<Self> {
+        if exp == 0 {
+            return Some(Fraction::ONE);
+        }
+        let base = if exp < 0 { self.reciprocal().ok()? } else { self };
+
+        // Exponentiation by squaring, the base is only squared again while bits of the exponent
+        // remain so an overflow is reported as soon as a needed multiplication doesn't fit
+        let mut base = base.checked_simplified()?;
+        let mut exp = exp.unsigned_abs();
+        let mut result = Fraction::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+
+    /// The nearest `f64` to the fraction, even when the numerator or denominator has more digits
+    /// than an `f64` can hold. The denominator can never be zero so there's no undefined value to
+    /// map to NaN, the result is always finite.
+    pub fn to_f64(&self) -> f64 {
+        if self.is_zero() {
+            return 0.0;
+        }
+        let (magnitude, exp) = self.scaled_magnitude(f64::MANTISSA_DIGITS + 2);
+        // Builds 2^exp directly from its bits, 1023 being the exponent bias of an f64
+        let result = magnitude as f64 * f64::from_bits(((1023 + exp) as u64) << 52);
+        if self.is_negative() { -result } else { result }
+    }
+
+    /// The nearest `f32` to the fraction, which like `to_f64` is never NaN
+    pub fn to_f32(&self) -> f32 {
+        if self.is_zero() {
+            return 0.0;
+        }
+        let (magnitude, exp) = self.scaled_magnitude(f32::MANTISSA_DIGITS + 2);
+        // 127 is the exponent bias of an f32
+        let result = magnitude as f32 * f32::from_bits(((127 + exp) as u32) << 23);
+        if self.is_negative() { -result } else { result }
+    }
+
+    /// The exact value of a finite float, which is always a fraction with a power of two
+    /// denominator, e.g. 0.1 is 3602879701896397/36028797018963968. Floats too large or too
+    /// close to zero for their exact value to fit in an i64 are an error rather than rounded, use
+    /// `limit_denominator` afterwards to find a simpler fraction.
+    pub fn from_f64_exact(x: f64) -> Result<Self, FromFloatError> {
+        if !x.is_finite() {
+            return Err(FromFloatError::NotFinite);
+        }
+
+        let (mantissa, exp) = decompose_f64(x);
+        if mantissa == 0 {
+            return Ok(Fraction::ZERO);
+        }
+
+        // An odd mantissa over a power of two is already in lowest terms
+        let zeros = mantissa.trailing_zeros();
+        let mantissa = (mantissa >> zeros) as i128;
+        let exp = exp + zeros as i32;
+        if exp.abs() > 64 {
+            return Err(FromFloatError::Overflow);
+        }
+        let (numerator, denominator) = if exp >= 0 {
+            (mantissa << exp, 1)
+        } else {
+            (mantissa, 1 << -exp)
+        };
+        let numerator = if x.is_sign_negative() { -numerator } else { numerator };
+        Fraction::narrow(numerator, denominator).ok_or(FromFloatError::Overflow)
+    }
+
+    /// The fraction with the smallest denominator within `max_error` of `x`, e.g. 0.1 becomes 1/10
+    /// rather than its exact binary value. Errors if `x` isn't finite, `max_error` isn't greater
+    /// than zero, or no fraction close enough fits in an i64.
+    pub fn approx_from_f64(x: f64, max_error: f64) -> Result<Self, FromFloatError> {
+        if !x.is_finite() {
+            return Err(FromFloatError::NotFinite);
+        }
+        if max_error.is_nan() || max_error <= 0.0 {
+            return Err(FromFloatError::InvalidTolerance);
+        }
+
+        let target = x.abs();
+        let sign = if x.is_sign_negative() { -1 } else { 1 };
+        let within = |(p, q): (i128, i128)| (Fraction::new(p as i64, q as i64).to_f64() - target).abs() <= max_error;
+        if within((0, 1)) {
+            return Ok(Fraction::ZERO);
+        }
+
+        // The simplest fraction in any range around x is one of the convergents or semiconvergents
+        // of x's continued fraction, which are visited here in order of increasing denominator
+        let mut convergents = Convergents::START;
+        let mut y = target;
+        loop {
+            // y is never negative and floats from 2^52 up are already whole numbers, so truncating
+            // floors it without `f64::floor`, which isn't available without std
+            let term = if y < (1u64 << 52) as f64 { y as i64 as f64 } else { y };
+
+            // Each semiconvergent with j steps is closer to x than the last, so the first one
+            // within the tolerance can be found with a binary search. Capping the steps keeps
+            // every one of them inside an i64.
+            let Convergents { p0, q0, p1, q1 } = convergents;
+            let limit = [(p0, p1), (q0, q1)].iter()
+                .filter(|(_, step)| *step > 0)
+                .map(|(start, step)| (i64::MAX as i128 - start) / step)
+                .min()
+                .unwrap();
+            let overflowed = term >= limit as f64;
+            let steps = if overflowed { limit } else { term as i128 };
+            let semiconvergent = |j: i128| convergents.semiconvergent(j).unwrap();
+            if steps >= 1 && within(semiconvergent(steps)) {
+                let (mut lo, mut hi) = (1, steps);
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    if within(semiconvergent(mid)) {
+                        hi = mid;
+                    } else {
+                        lo = mid + 1;
+                    }
+                }
+                let (numerator, denominator) = semiconvergent(lo);
+                return Ok(Fraction::new(sign * numerator as i64, denominator as i64));
+            }
+            if overflowed {
+                return Err(FromFloatError::Overflow);
+            }
+            convergents = convergents.next(steps).unwrap();
+
+            // The expansion only ends early when rounding errors in y leave the convergents short
+            // of a tolerance finer than the float itself, and then only the exact value is close
+            let remainder = y - term;
+            if remainder == 0.0 {
+                return Fraction::from_f64_exact(x);
+            }
+            y = 1.0 / remainder;
+        }
+    }
+
+    /// LaTeX for the reduced fraction, e.g. `-\frac{3}{4}` with the minus sign outside the
+    /// fraction, or just the numerator when the denominator is one
+    pub fn to_latex(&self) -> String {
+        let (numerator, denominator) = self.reduced_wide();
+        if denominator == 1 {
+            numerator.to_string()
+        } else {
+            let sign = if numerator < 0 { "-" } else { "" };
+            format!("{}\\frac{{{}}}{{{}}}", sign, numerator.unsigned_abs(), denominator)
+        }
+    }
+
+    /// Same as `to_latex` with improper fractions written as mixed numbers, e.g.
+    /// `1\,\frac{3}{4}`, where `\,` is a thin space that works in math mode
+    pub fn to_latex_mixed(&self) -> String {
+        let (numerator, denominator) = self.reduced_wide();
+        let (whole, remainder) = (numerator / denominator, (numerator % denominator).abs());
+        match (whole, remainder) {
+            (0, _) | (_, 0) => self.to_latex(),
+            _ => format!("{}\\,\\frac{{{}}}{{{}}}", whole, remainder, denominator),
+        }
+    }
+
+    /// The reduced fraction with a precomposed vulgar fraction character like `¾` where Unicode
+    /// has one, otherwise superscript and subscript digits around a fraction slash like `⁵⁄₁₇`
+    pub fn to_unicode_string(&self) -> String {
+        let (numerator, denominator) = self.reduced_wide();
+        let sign = if numerator < 0 { "-" } else { "" };
+        format!("{}{}", sign, unicode_fraction(numerator.unsigned_abs(), denominator.unsigned_abs()))
+    }
+
+    /// Same as `to_unicode_string` with improper fractions written as mixed numbers, e.g. `2¾`
+    pub fn to_unicode_string_mixed(&self) -> String {
+        let (numerator, denominator) = self.reduced_wide();
+        let (magnitude, denominator) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+        let sign = if numerator < 0 { "-" } else { "" };
+        let (whole, remainder) = (magnitude / denominator, magnitude % denominator);
+        match (whole, remainder) {
+            (0, _) | (_, 0) => self.to_unicode_string(),
+            _ => format!("{}{}{}", sign, whole, unicode_fraction(remainder, denominator)),
+        }
+    }
+
+    /// Adds up fractions or references to fractions, returning an error instead of panicking on
+    /// overflow like `Sum` does
+    pub fn try_sum<I>(iter: I) -> Result<Self, FractionOverflow>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Fraction>,
+    {
+        iter.into_iter().try_fold(Fraction::ZERO, |sum, f| {
+            sum.checked_add(*f.borrow()).ok_or(FractionOverflow)
+        })
+    }
+
+    /// Multiplies fractions or references to fractions, returning an error instead of panicking on
+    /// overflow like `Product` does
+    pub fn try_product<I>(iter: I) -> Result<Self, FractionOverflow>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Fraction>,
+    {
+        iter.into_iter().try_fold(Fraction::ONE, |product, f| {
+            product.checked_mul(*f.borrow()).ok_or(FractionOverflow)
+        })
+    }
+
+    /// The mediant (a+c)/(b+d) of a/b and c/d, which always lies between the two fractions.
+    /// It's only meaningful for fractions in lowest terms with positive denominators, e.g.
+    /// 1/2 and 2/4 are equal but have different mediants with 1/3, so both inputs are reduced
+    /// first. Panics on overflow, use `checked_mediant` to handle it.
+    pub fn mediant(&self, other: &Fraction) -> Fraction {
+        self.checked_mediant(other).expect("attempt to take the mediant of fractions with overflow")
+    }
+
+    /// Returns `None` if the numerator or denominator of the result would overflow
+    pub fn checked_mediant(&self, other: &Fraction) -> Option<Fraction> {
+        let (n1, d1) = self.widen_signed_numerator();
+        let (n2, d2) = other.widen_signed_numerator();
+        let (n1, d1) = Fraction::narrow(n1, d1)?.widen();
+        let (n2, d2) = Fraction::narrow(n2, d2)?.widen();
+        Fraction::narrow(n1 + n2, d1 + d2)
+    }
+
+    /// A fraction strictly between two distinct fractions, found with the mediant so it has a
+    /// small denominator. Panics if the fractions are equal or on overflow.
+    pub fn between(&self, other: &Fraction) -> Fraction {
+        assert!(self != other, "attempt to find a fraction between two equal fractions");
+        self.mediant(other)
+    }
+
+    /// The largest fraction that divides both fractions a whole number of times, which is the
+    /// gcd of the numerators over the lcm of the denominators once both are in lowest terms,
+    /// e.g. 2/9 for 2/3 and 4/9. Never negative. Panics on overflow, use `checked_gcd` to handle it.
+    pub fn gcd(&self, other: &Fraction) -> Fraction {
+        self.checked_gcd(other).expect("attempt to calculate the gcd of fractions with overflow")
+    }
+
+    /// Returns `None` if the denominator of the result would overflow
+    pub fn checked_gcd(&self, other: &Fraction) -> Option<Fraction> {
+        let (n1, d1) = self.reduced_wide();
+        let (n2, d2) = other.reduced_wide();
+        Fraction::narrow(gcd_wide(n1, n2), d1 / gcd_wide(d1, d2) * d2)
+    }
+
+    /// The smallest fraction that both fractions divide a whole number of times, which is the
+    /// lcm of the numerators over the gcd of the denominators once both are in lowest terms,
+    /// e.g. 4/3 for 2/3 and 4/9. Never negative, and zero if either fraction is zero. Panics on
+    /// overflow, use `checked_lcm` to handle it.
+    pub fn lcm(&self, other: &Fraction) -> Fraction {
+        self.checked_lcm(other).expect("attempt to calculate the lcm of fractions with overflow")
+    }
+
+    /// Returns `None` if the numerator of the result would overflow
+    pub fn checked_lcm(&self, other: &Fraction) -> Option<Fraction> {
+        let (n1, d1) = self.reduced_wide();
+        let (n2, d2) = other.reduced_wide();
+        if n1 == 0 || n2 == 0 {
+            return Some(Fraction::ZERO);
+        }
+        Fraction::narrow((n1 / gcd_wide(n1, n2) * n2).abs(), gcd_wide(d1, d2))
+    }
+
+    /// The partial quotients [a0; a1, a2, ...] of the fraction's continued fraction, found with
+    /// the Euclidean algorithm. The first term is the floor of the fraction so it's negative for
+    /// negative fractions, the rest are positive and the last is always greater than one unless
+    /// the fraction is an integer. Panics for i64::MIN / -1 whose floor doesn't fit in an i64.
+    pub fn continued_fraction(&self) -> Vec<i64> {
+        let (mut numerator, mut denominator) = self.widen_signed_numerator();
+        let mut terms = Vec::new();
+        while denominator != 0 {
+            let term = numerator.div_euclid(denominator);
+            terms.push(i64::try_from(term).expect("attempt to expand a fraction with overflow"));
+            let remainder = numerator - term * denominator;
+            numerator = denominator;
+            denominator = remainder;
+        }
+        terms
+    }
+
+    /// Builds a fraction back from the partial quotients of its continued fraction. Panics if
+    /// there are no terms, on overflow, or if the terms describe a division by zero.
+    pub fn from_continued_fraction(terms: &[i64]) -> Fraction {
+        assert!(!terms.is_empty(), "attempt to build a fraction from an empty continued fraction");
+
+        let Convergents { p1, q1, .. } = terms.iter()
+            .try_fold(Convergents::START, |convergents, &term| convergents.next(term as i128))
+            .expect("attempt to build a fraction from a continued fraction with overflow");
+        if q1 == 0 {
+            panic!("attempt to build a fraction with a zero denominator from a continued fraction");
+        }
+        Fraction::narrow(p1, q1).expect("attempt to build a fraction from a continued fraction with overflow")
+    }
+
+    /// The closest fraction to this one with a denominator of at most `max_denominator`, like
+    /// Python's `Fraction.limit_denominator`. When two candidates are equally close the one with
+    /// the smaller denominator is returned. Panics if `max_denominator` is less than one.
+    pub fn limit_denominator(&self, max_denominator: i64) -> Fraction {
+        assert!(max_denominator >= 1, "attempt to limit the denominator of a fraction to less than one");
+
+        let (numerator, denominator) = self.widen_signed_numerator();
+        let reduced = Fraction::narrow(numerator, denominator)
+            .expect("attempt to limit the denominator of a fraction with overflow");
+        if reduced.denominator <= max_denominator {
+            return reduced;
+        }
+
+        // Walk the convergents of the continued fraction until the next one's denominator would
+        // be too large. The last convergent is the fraction itself so this always stops early.
+        let max = max_denominator as i128;
+        let mut convergents = Convergents::START;
+        for term in reduced.continued_fraction() {
+            match convergents.next(term as i128) {
+                Some(next) if next.q1 <= max => convergents = next,
+                _ => break,
+            }
+        }
+
+        // The best approximation is either the last convergent or the largest semiconvergent
+        // that fits. Each is |p * d - q * n| / (q * d) away from n/d, so the distances are
+        // compared with the shared d cancelled out and cross-multiplied by the other's q.
+        let Convergents { q0, p1, q1, .. } = convergents;
+        let (semi_numerator, semi_denominator) = convergents.semiconvergent((max - q0) / q1).unwrap();
+        let (n, d) = reduced.widen();
+        let distance = |p: i128, q: i128| (p * d - q * n).unsigned_abs();
+        let convergent_distance = distance(p1, q1) * semi_denominator as u128;
+        let semi_distance = distance(semi_numerator, semi_denominator) * q1 as u128;
+        let (numerator, denominator) = match convergent_distance.cmp(&semi_distance) {
+            Ordering::Less => (p1, q1),
+            Ordering::Greater => (semi_numerator, semi_denominator),
+            Ordering::Equal if q1 <= semi_denominator => (p1, q1),
+            Ordering::Equal => (semi_numerator, semi_denominator),
+        };
+        Fraction::narrow(numerator, denominator)
+            .expect("attempt to limit the denominator of a fraction with overflow")
+    }
+
+    /// The smaller of the two fractions without simplifying either. Returns `self` when they're
+    /// equal, like `Ord::min`.
+    pub fn min(self, other: Self) -> Self {
+        match self.cmp(&other) {
+            Ordering::Greater => other,
+            _ => self,
+        }
+    }
+
+    /// The larger of the two fractions without simplifying either. Returns `other` when they're
+    /// equal, like `Ord::max`.
+    pub fn max(self, other: Self) -> Self {
+        match self.cmp(&other) {
+            Ordering::Greater => self,
+            _ => other,
+        }
+    }
+
+    /// Restricts the fraction to the range `lo..=hi`, returning `self` unchanged when it's inside
+    /// the range or equal to one of the bounds. Panics if `lo > hi`.
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        assert!(lo <= hi, "attempt to clamp a fraction with a lower bound above the upper bound");
+        if self < lo {
+            lo
+        } else if self > hi {
+            hi
+        } else {
+            self
+        }
+    }
+
+    // A terminating decimal is its digits over a power of ten, and repeating digits add
+    // repeating / (10^fractional digits * (10^repeating digits - 1)) on top, e.g. 0.(3) = 3/9
+    fn from_decimal(decimal: &Decimal) -> Option<Self> {
+        let digits = |s: &str| if s.is_empty() { Some(0) } else { s.parse::<i128>().ok() };
+        let scale = 10i128.checked_pow(decimal.fractional.len() as u32)?;
+        let nines = 10i128.checked_pow(decimal.repeating.len() as u32)? - 1;
+        let (numerator, denominator) = if nines == 0 {
+            (digits(decimal.fractional)?, scale)
+        } else {
+            let numerator = digits(decimal.fractional)?.checked_mul(nines)?.checked_add(digits(decimal.repeating)?)?;
+            (numerator, scale.checked_mul(nines)?)
+        };
+        let magnitude = digits(decimal.whole)?.checked_mul(denominator)?.checked_add(numerator)?;
+        Fraction::narrow(if decimal.negative { -magnitude } else { magnitude }, denominator)
+    }
+
+    // Scales the fraction by the denominator and rounds it to a whole number of steps with the
+    // given function, which gets a numerator and a positive denominator
+    fn snap_to_multiple<F: Fn(i128, i128) -> i128>(&self, denominator: i64, round: F) -> Fraction {
+        assert!(denominator >= 1, "attempt to round to a multiple of 1/n with n less than one");
+        let (numerator, divisor) = self.widen_signed_numerator();
+        let steps = round(numerator * denominator as i128, divisor);
+        let steps = i64::try_from(steps).expect("attempt to round a fraction with overflow");
+        Fraction::new(steps, denominator)
+    }
+
+    // Reduced with the sign on the numerator, which can't overflow at this width
+    fn reduced_wide(&self) -> (i128, i128) {
+        let (numerator, denominator) = self.widen_signed_numerator();
+        let gcd = gcd_wide(numerator, denominator);
+        (numerator / gcd, denominator / gcd)
+    }
+
+    // The whole part with the sign of the fraction, and the positive remainder and denominator
+    fn mixed_parts(&self) -> (i128, i128, i128) {
+        let (numerator, denominator) = self.widen_signed_numerator();
+        (numerator / denominator, (numerator % denominator).abs(), denominator)
+    }
+
+    // Long division one digit at a time so the digits are exact no matter how large the numerator
+    // and denominator are. A result that rounds to zero is written without a minus sign.
+    fn decimal_string(&self, precision: usize) -> String {
+        let (numerator, denominator) = self.widen_signed_numerator();
+        let mut whole = numerator.unsigned_abs() / denominator as u128;
+        let mut remainder = numerator.unsigned_abs() % denominator as u128;
+        let mut digits = Vec::with_capacity(precision);
+        for _ in 0..precision {
+            remainder *= 10;
+            digits.push((remainder / denominator as u128) as u8);
+            remainder %= denominator as u128;
+        }
+
+        // Round half up on the magnitude by looking at the next digit, carrying into the whole part
+        if remainder * 10 / denominator as u128 >= 5 {
+            let mut carry = true;
+            for digit in digits.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    carry = false;
+                    break;
+                }
+            }
+            if carry {
+                whole += 1;
+            }
+        }
+
+        let is_zero = whole == 0 && digits.iter().all(|&digit| digit == 0);
+        let mut result = String::new();
+        if numerator < 0 && !is_zero {
+            result.push('-');
+        }
+        result.push_str(&whole.to_string());
+        if precision > 0 {
+            result.push('.');
+            result.extend(digits.iter().map(|&digit| (b'0' + digit) as char));
+        }
+        result
+    }
+
+    // The magnitude of a non-zero fraction as an integer of `bits` or `bits + 1` bits times 2^exp.
+    // Any bits lost to the division are folded into the lowest bit, so converting the integer to
+    // a float with at least two fewer bits of mantissa rounds the same way the exact value would.
+    fn scaled_magnitude(&self, bits: u32) -> (u64, i32) {
+        let numerator = self.numerator.unsigned_abs() as u128;
+        let denominator = self.denominator.unsigned_abs() as u128;
+        let shift = bits as i32 + numerator.leading_zeros() as i32 - denominator.leading_zeros() as i32;
+        let (numerator, denominator) = if shift >= 0 {
+            (numerator << shift, denominator)
+        } else {
+            (numerator, denominator << -shift)
+        };
+        let quotient = numerator / denominator;
+        let inexact = (numerator % denominator != 0) as u128;
+        ((quotient | inexact) as u64, -shift)
+    }
+
+    // Products of two i64 values always fit in an i128, so intermediate results are computed there
+    fn widen(&self) -> (i128, i128) {
+        (self.numerator as i128, self.denominator as i128)
+    }
+
+    // Widens with the sign moved onto the numerator, which can't overflow in an i128
+    fn widen_signed_numerator(&self) -> (i128, i128) {
+        let (numerator, denominator) = self.widen();
+        if denominator < 0 {
+            (-numerator, -denominator)
+        } else {
+            (numerator, denominator)
+        }
+    }
+
+    // Numerators of both fractions over the least common multiple of their denominators, which
+    // is positive and keeps the intermediate values small, e.g. 1/6 + 1/10 is worked out over 30
+    // rather than 60. Can't overflow since each is at most a product of two i64 values.
+    fn over_common_denominator(&self, other: Self) -> (i128, i128, i128) {
+        let (n1, d1) = self.widen_signed_numerator();
+        let (n2, d2) = other.widen_signed_numerator();
+        let gcd = gcd_wide(d1, d2);
+        (n1 * (d2 / gcd), n2 * (d1 / gcd), d1 / gcd * d2)
+    }
+
+    // The i128 paths `checked_add` and `checked_mul` fall back to. They're only public so the
+    // arithmetic benchmark can measure the fast paths against always working in i128.
+    #[doc(hidden)]
+    pub fn checked_add_wide(self, other: Self) -> Option<Self> {
+        let (n1, n2, denominator) = self.over_common_denominator(other);
+        Fraction::narrow(n1.checked_add(n2)?, denominator)
+    }
+
+    #[doc(hidden)]
+    pub fn checked_mul_wide(self, other: Self) -> Option<Self> {
+        let (n1, d1) = self.widen();
+        let (n2, d2) = other.widen();
+        Fraction::narrow(n1 * n2, d1 * d2)
+    }
+
+    // The i64 fast path for `checked_add` and `checked_sub`, which gives up as soon as anything
+    // overflows so the caller can redo the whole thing in i128
+    fn add_small(self, other: Self, subtract: bool) -> Option<Self> {
+        let gcd = gcd(self.denominator, other.denominator);
+        let n1 = self.numerator.checked_mul(other.denominator / gcd)?;
+        let n2 = other.numerator.checked_mul(self.denominator / gcd)?;
+        let numerator = if subtract { n1.checked_sub(n2)? } else { n1.checked_add(n2)? };
+        Fraction::narrow_small(numerator, (self.denominator / gcd).checked_mul(other.denominator)?)
+    }
+
+    // The i64 fast path for `checked_mul`, see `add_small`
+    fn mul_small(self, other: Self) -> Option<Self> {
+        let numerator = self.numerator.checked_mul(other.numerator)?;
+        let denominator = self.denominator.checked_mul(other.denominator)?;
+        Fraction::narrow_small(numerator, denominator)
+    }
+
+    // Same as `narrow` for a result that fit in an i64 all along. The gcd only wraps around to
+    // i64::MIN when it divides both values exactly, and the signs are fixed up afterwards anyway.
+    fn narrow_small(numerator: i64, denominator: i64) -> Option<Self> {
+        let gcd = gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / gcd, denominator / gcd);
+        if denominator < 0 {
+            Fraction::try_new(numerator.checked_neg()?, denominator.checked_neg()?).ok()
+        } else {
+            Fraction::try_new(numerator, denominator).ok()
+        }
+    }
+
+    // Simplifies an intermediate result and only fails if it still doesn't fit back into an i64
+    fn narrow(mut numerator: i128, mut denominator: i128) -> Option<Self> {
+        let gcd = gcd_wide(numerator, denominator);
+        if gcd > 1 {
+            numerator /= gcd;
+            denominator /= gcd;
+        }
+        if denominator < 0 {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+        let numerator = i64::try_from(numerator).ok()?;
+        let denominator = i64::try_from(denominator).ok()?;
+        Fraction::try_new(numerator, denominator).ok()
+    }
+}
+
+impl Display for Fraction {
+    /// Written without simplifying but with the sign moved onto the numerator, so 1/-2 is
+    /// "-1/2" and -2/-4 is "2/4". The alternate form `{:#}` writes improper fractions as mixed
+    /// numbers with the sign on the whole part, e.g. -7/4 is "-1 3/4". A precision like `{:.4}`
+    /// writes the fraction as a decimal rounded half away from zero to that many places instead,
+    /// e.g. 1/3 is "0.3333".
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(precision) = f.precision() {
+            write!(f, "{}", self.decimal_string(precision))
+        } else if f.alternate() && self.denominator != 1 && !self.is_proper() {
+            let (whole, remainder, denominator) = self.mixed_parts();
+            if remainder == 0 {
+                write!(f, "{}", whole)
+            } else {
+                write!(f, "{} {}/{}", whole, remainder, denominator)
+            }
+        } else {
+            let (numerator, denominator) = self.widen_signed_numerator();
+            if denominator == 1 {
+                write!(f, "{}", numerator)
+            } else {
+                write!(f, "{}/{}", numerator, denominator)
+            }
+        }
+    }
+}
+
+impl FromStr for Fraction {
+    type Err = ParseFractionError;
+
+    /// Accepts integers, fractions like "-3/4", mixed numbers like "1 3/4" or "-2_1/2", where
+    /// the fractional part of a mixed number has to be proper and takes the sign of the whole
+    /// part, and decimals like
+    /// "-2.25" or "0.1(6)" with the repeating digits in parentheses. "0.333..." isn't accepted
+    /// since it doesn't say which digits repeat. Any of these can end in a percent sign to be
+    /// divided by 100, e.g. "12.5%" is 1/8.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(number) = split_percent(s)? {
+            let percent = Fraction::from_str(number)?;
+            return percent.checked_mul(Fraction::new(1, 100)).ok_or(ParseFractionError::Overflow);
+        }
+
+        if let Some(mixed) = split_mixed(s)? {
+            let whole = parse_digits(mixed.whole)?;
+            let fraction = Fraction::try_new(parse_digits(mixed.numerator)?, parse_digits(mixed.denominator)?)?;
+
+            let (numerator, denominator) = fraction.widen();
+            let magnitude = whole as i128 * denominator + numerator;
+            let numerator = if mixed.negative { -magnitude } else { magnitude };
+            return Fraction::narrow(numerator, denominator).ok_or(ParseFractionError::Overflow);
+        }
+
+        if let Some(decimal) = split_decimal(s)? {
+            return Fraction::from_decimal(&decimal).ok_or(ParseFractionError::Overflow);
+        }
+
+        match split_fraction(s)? {
+            (numerator, Some(denominator)) => {
+                Ok(Fraction::try_new(parse_digits(numerator)?, parse_digits(denominator)?)?)
+            }
+            (num, None) => Ok(Fraction::from(parse_digits(num)?)),
+        }
+    }
+}
+
+// The parse module has already checked these are digits, so they can only fail by overflowing
+fn parse_digits(digits: &str) -> Result<i64, ParseFractionError> {
+    digits.parse().map_err(|_| ParseFractionError::Overflow)
+}
+
+// The magnitude of a finite float as mantissa * 2^exp, with the implicit leading one only
+// present on normal floats
+fn decompose_f64(x: f64) -> (u64, i32) {
+    let bits = x.to_bits();
+    let biased_exp = ((bits >> 52) & 0x7ff) as i32;
+    let fraction_bits = bits & ((1 << 52) - 1);
+    if biased_exp == 0 {
+        (fraction_bits, -1074)
+    } else {
+        (fraction_bits | 1 << 52, biased_exp - 1075)
+    }
+}
+
+// Two consecutive convergents p0/q0 and p1/q1 of a continued fraction, shared by the methods that
+// build fractions from one term at a time. Before the first term they're 0/1 and 1/0.
+#[derive(Clone, Copy)]
+struct Convergents {
+    p0: i128,
+    q0: i128,
+    p1: i128,
+    q1: i128,
+}
+
+impl Convergents {
+    const START: Convergents = Convergents { p0: 0, q0: 1, p1: 1, q1: 0 };
+
+    // The semiconvergent (p0 + k * p1) / (q0 + k * q1), which is the next convergent when k is
+    // the next term. `None` on overflow.
+    fn semiconvergent(&self, k: i128) -> Option<(i128, i128)> {
+        let p = k.checked_mul(self.p1)?.checked_add(self.p0)?;
+        let q = k.checked_mul(self.q1)?.checked_add(self.q0)?;
+        Some((p, q))
+    }
+
+    fn next(self, term: i128) -> Option<Convergents> {
+        let (p2, q2) = self.semiconvergent(term)?;
+        Some(Convergents { p0: self.p1, q0: self.q1, p1: p2, q1: q2 })
+    }
+}
+
+// Every fraction with a precomposed character in Unicode, already in lowest terms
+const VULGAR_FRACTIONS: [(u128, u128, char); 18] = [
+    (1, 2, '½'), (1, 3, '⅓'), (2, 3, '⅔'), (1, 4, '¼'), (3, 4, '¾'), (1, 5, '⅕'),
+    (2, 5, '⅖'), (3, 5, '⅗'), (4, 5, '⅘'), (1, 6, '⅙'), (5, 6, '⅚'), (1, 7, '⅐'),
+    (1, 8, '⅛'), (3, 8, '⅜'), (5, 8, '⅝'), (7, 8, '⅞'), (1, 9, '⅑'), (1, 10, '⅒'),
+];
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+const SUBSCRIPT_DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+// The magnitude of a reduced fraction without its sign
+fn unicode_fraction(numerator: u128, denominator: u128) -> String {
+    if denominator == 1 {
+        return numerator.to_string();
+    }
+    if let Some(&(_, _, c)) = VULGAR_FRACTIONS.iter().find(|&&(n, d, _)| (n, d) == (numerator, denominator)) {
+        return c.to_string();
+    }
+
+    let map_digits = |num: u128, digits: &[char; 10]| -> String {
+        num.to_string().bytes().map(|b| digits[(b - b'0') as usize]).collect()
+    };
+    format!("{}\u{2044}{}", map_digits(numerator, &SUPERSCRIPT_DIGITS), map_digits(denominator, &SUBSCRIPT_DIGITS))
+}
+
+impl From<i32> for Fraction {
+    fn from(num: i32) -> Self {
+        Fraction::from_int(num as i64)
+    }
+}
+
+impl From<i64> for Fraction {
+    fn from(num: i64) -> Self {
+        Fraction::from_int(num)
+    }
+}
+
+impl From<i16> for Fraction {
+    fn from(num: i16) -> Self {
+        Fraction::from_int(num as i64)
+    }
+}
+
+impl From<u16> for Fraction {
+    fn from(num: u16) -> Self {
+        Fraction::from_int(num as i64)
+    }
+}
+
+impl From<u8> for Fraction {
+    fn from(num: u8) -> Self {
+        Fraction::from_int(num as i64)
+    }
+}
+
+impl From<Fraction> for f64 {
+    fn from(f: Fraction) -> Self {
+        f.to_f64()
+    }
+}
+
+impl From<Fraction> for f32 {
+    fn from(f: Fraction) -> Self {
+        f.to_f32()
+    }
+}
+
+impl TryFrom<(i32, i32)> for Fraction {
+    type Error = ZeroDenominator;
+
+    /// Moves the sign onto the numerator, an i32 can always be negated once widened to i64
+    fn try_from((numerator, denominator): (i32, i32)) -> Result<Self, Self::Error> {
+        let (numerator, denominator) = (numerator as i64, denominator as i64);
+        if denominator < 0 {
+            Fraction::try_new(-numerator, -denominator)
+        } else {
+            Fraction::try_new(numerator, denominator)
+        }
+    }
+}
+
+impl Default for Fraction {
+    fn default() -> Self {
+        Fraction::ZERO
+    }
+}
+
+impl Add for Fraction {
+    type Output = Self;
+
+    /// Panics on overflow, use `checked_add` to handle it
+    fn add(self, other: Self) -> Self::Output {
+        self.checked_add(other).expect("attempt to add fractions with overflow")
+    }
+}
+
+impl Sub for Fraction {
+    type Output = Self;
+
+    /// Panics on overflow, use `checked_sub` to handle it
+    fn sub(self, other: Self) -> Self::Output {
+        self.checked_sub(other).expect("attempt to subtract fractions with overflow")
+    }
+}
+
+impl Neg for Fraction {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("attempt to negate a fraction with overflow")
+    }
+}
+
+impl Mul for Fraction {
+    type Output = Self;
+
+    /// Panics on overflow, use `checked_mul` to handle it
+    fn mul(self, other: Self) -> Self::Output {
+        self.checked_mul(other).expect("attempt to multiply fractions with overflow")
+    }
+}
+
+impl Div for Fraction {
+    type Output = Self;
+
+    /// Panics on overflow or division by zero, use `checked_div` to handle them
+    fn div(self, other: Self) -> Self::Output {
+        match other.reciprocal() {
+            Ok(reciprocal) => self.checked_mul(reciprocal).expect("attempt to divide fractions with overflow"),
+            Err(_) => panic!("attempt to divide a fraction by zero"),
+        }
+    }
+}
+
+impl Rem for Fraction {
+    type Output = Self;
+
+    /// Panics on overflow or if `other` is zero, use `checked_rem` to handle them
+    fn rem(self, other: Self) -> Self::Output {
+        if other.is_zero() {
+            panic!("attempt to calculate the remainder of a fraction with a divisor of zero");
+        }
+        self.checked_rem(other).expect("attempt to calculate the remainder of fractions with overflow")
+    }
+}
+
+impl Neg for &Fraction {
+    type Output = Fraction;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+// Arithmetic on references, e.g. `&a + &b`, `&a + b` and `a + &b`, all share the by-value impls
+macro_rules! impl_ref_ops {
+    ($($op:ident, $method:ident);*) => {$(
+        impl $op<&Fraction> for &Fraction {
+            type Output = Fraction;
+
+            fn $method(self, other: &Fraction) -> Self::Output {
+                (*self).$method(*other)
+            }
+        }
+
+        impl $op<Fraction> for &Fraction {
+            type Output = Fraction;
+
+            fn $method(self, other: Fraction) -> Self::Output {
+                (*self).$method(other)
+            }
+        }
+
+        impl $op<&Fraction> for Fraction {
+            type Output = Fraction;
+
+            fn $method(self, other: &Fraction) -> Self::Output {
+                self.$method(*other)
+            }
+        }
+    )*};
+}
+
+impl_ref_ops!(Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+// Arithmetic between fractions and integers on either side, e.g. `f + 3` and `3 + f`
+macro_rules! impl_int_ops {
+    ($($int:ty),*) => {$(
+        impl Add<$int> for Fraction {
+            type Output = Fraction;
+
+            fn add(self, other: $int) -> Self::Output {
+                self + Fraction::from(other)
+            }
+        }
+
+        impl Add<Fraction> for $int {
+            type Output = Fraction;
+
+            fn add(self, other: Fraction) -> Self::Output {
+                Fraction::from(self) + other
+            }
+        }
+
+        impl Sub<$int> for Fraction {
+            type Output = Fraction;
+
+            fn sub(self, other: $int) -> Self::Output {
+                self - Fraction::from(other)
+            }
+        }
+
+        impl Sub<Fraction> for $int {
+            type Output = Fraction;
+
+            fn sub(self, other: Fraction) -> Self::Output {
+                Fraction::from(self) - other
+            }
+        }
+
+        impl Mul<$int> for Fraction {
+            type Output = Fraction;
+
+            fn mul(self, other: $int) -> Self::Output {
+                self * Fraction::from(other)
+            }
+        }
+
+        impl Mul<Fraction> for $int {
+            type Output = Fraction;
+
+            fn mul(self, other: Fraction) -> Self::Output {
+                Fraction::from(self) * other
+            }
+        }
+
+        impl Div<$int> for Fraction {
+            type Output = Fraction;
+
+            fn div(self, other: $int) -> Self::Output {
+                self / Fraction::from(other)
+            }
+        }
+
+        impl Div<Fraction> for $int {
+            type Output = Fraction;
+
+            fn div(self, other: Fraction) -> Self::Output {
+                Fraction::from(self) / other
+            }
+        }
+
+        impl Rem<$int> for Fraction {
+            type Output = Fraction;
+
+            fn rem(self, other: $int) -> Self::Output {
+                self % Fraction::from(other)
+            }
+        }
+
+        impl Rem<Fraction> for $int {
+            type Output = Fraction;
+
+            fn rem(self, other: Fraction) -> Self::Output {
+                Fraction::from(self) % other
+            }
+        }
+    )*};
+}
+
+impl_int_ops!(i32, i64);
+
+impl AddAssign for Fraction {
+    /// Panics on overflow, use `checked_add` to handle it
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Fraction {
+    /// Panics on overflow, use `checked_sub` to handle it
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Fraction {
+    /// Panics on overflow, use `checked_mul` to handle it
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for Fraction {
+    /// Panics on overflow or division by zero, use `checked_div` to handle them
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl RemAssign for Fraction {
+    /// Panics on overflow or if `other` is zero, use `checked_rem` to handle them
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+    }
+}
+
+impl AddAssign<i32> for Fraction {
+    fn add_assign(&mut self, other: i32) {
+        *self += Fraction::from(other);
+    }
+}
+
+impl SubAssign<i32> for Fraction {
+    fn sub_assign(&mut self, other: i32) {
+        *self -= Fraction::from(other);
+    }
+}
+
+impl MulAssign<i32> for Fraction {
+    fn mul_assign(&mut self, other: i32) {
+        *self *= Fraction::from(other);
+    }
+}
+
+impl DivAssign<i32> for Fraction {
+    fn div_assign(&mut self, other: i32) {
+        *self /= Fraction::from(other);
+    }
+}
+
+impl RemAssign<i32> for Fraction {
+    fn rem_assign(&mut self, other: i32) {
+        *self %= Fraction::from(other);
+    }
+}
+
+impl Sum for Fraction {
+    /// Panics on overflow, use `Fraction::try_sum` to handle it
+    fn sum<I: Iterator<Item = Fraction>>(iter: I) -> Self {
+        Fraction::try_sum(iter).expect("attempt to add fractions with overflow")
+    }
+}
+
+impl<'a> Sum<&'a Fraction> for Fraction {
+    /// Panics on overflow, use `Fraction::try_sum` to handle it
+    fn sum<I: Iterator<Item = &'a Fraction>>(iter: I) -> Self {
+        Fraction::try_sum(iter).expect("attempt to add fractions with overflow")
+    }
+}
+
+impl Product for Fraction {
+    /// Panics on overflow, use `Fraction::try_product` to handle it
+    fn product<I: Iterator<Item = Fraction>>(iter: I) -> Self {
+        Fraction::try_product(iter).expect("attempt to multiply fractions with overflow")
+    }
+}
+
+impl<'a> Product<&'a Fraction> for Fraction {
+    /// Panics on overflow, use `Fraction::try_product` to handle it
+    fn product<I: Iterator<Item = &'a Fraction>>(iter: I) -> Self {
+        Fraction::try_product(iter).expect("attempt to multiply fractions with overflow")
+    }
+}
+
+impl PartialEq for Fraction {
+    fn eq(&self, other: &Self) -> bool {
+        // Cross multiplying is enough without simplifying, and the signs of the denominators
+        // don't matter since n1/d1 == n2/d2 exactly when n1 * d2 == n2 * d1
+        let (n1, d1) = self.widen();
+        let (n2, d2) = other.widen();
+        n1 * d2 == n2 * d1
+    }
+}
+
+impl PartialEq<i32> for Fraction {
+    fn eq(&self, other: &i32) -> bool {
+        *self == Fraction::from(*other)
+    }
+}
+
+impl PartialEq<Fraction> for i32 {
+    fn eq(&self, other: &Fraction) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<i64> for Fraction {
+    fn eq(&self, other: &i64) -> bool {
+        *self == Fraction::from(*other)
+    }
+}
+
+impl PartialEq<Fraction> for i64 {
+    fn eq(&self, other: &Fraction) -> bool {
+        other == self
+    }
+}
+
+impl Eq for Fraction {}
+
+impl Hash for Fraction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal fractions have to hash the same, so hash the simplified form with a positive
+        // denominator. Working in i128 avoids overflowing when negating i64::MIN.
+        let (mut numerator, mut denominator) = self.widen();
+        let gcd = gcd_wide(numerator, denominator);
+        numerator /= gcd;
+        denominator /= gcd;
+        if denominator < 0 {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+        numerator.hash(state);
+        denominator.hash(state);
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Move the signs onto the numerators so cross multiplying doesn't flip the comparison,
+        // i128 fits the products of any two i64 values
+        let (n1, d1) = self.widen_signed_numerator();
+        let (n2, d2) = other.widen_signed_numerator();
+        (n1 * d2).cmp(&(n2 * d1))
+    }
+}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialOrd<i32> for Fraction {
+    fn partial_cmp(&self, other: &i32) -> Option<Ordering> {
+        self.partial_cmp(&Fraction::from(*other))
+    }
+}
+
+impl PartialOrd<Fraction> for i32 {
+    fn partial_cmp(&self, other: &Fraction) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl PartialOrd<i64> for Fraction {
+    fn partial_cmp(&self, other: &i64) -> Option<Ordering> {
+        self.partial_cmp(&Fraction::from(*other))
+    }
+}
+
+impl PartialOrd<Fraction> for i64 {
+    fn partial_cmp(&self, other: &Fraction) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+/// Compares against the exact value of the float, so 1/3 != 1.0 / 3.0 since the float is
+/// slightly less than a third, and i64::MAX != 2^63 even though i64::MAX rounds to 2^63. This
+/// holds for every float, including ones far outside the range of a `Fraction` like 1e300.
+impl PartialEq<f64> for Fraction {
+    fn eq(&self, other: &f64) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd<f64> for Fraction {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        if other.is_nan() {
+            return None;
+        }
+        if other.is_infinite() {
+            return Some(if *other > 0.0 { Ordering::Less } else { Ordering::Greater });
+        }
+
+        let (numerator, denominator) = self.widen_signed_numerator();
+        let (mantissa, exp) = decompose_f64(*other);
+        let float_sign = if mantissa == 0 { 0 } else if other.is_sign_negative() { -1 } else { 1 };
+        match numerator.signum().cmp(&float_sign) {
+            Ordering::Equal if float_sign != 0 => {}
+            ordering => return Some(ordering),
+        }
+
+        // Both have the same sign, so compare |n| / d with mantissa * 2^exp by cross multiplying
+        // in u128. A shift that would lose bits means that side is at least 2^128, which is more
+        // than the other side can ever be since it's at most a 64 bit value times a 53 bit one.
+        let magnitude = numerator.unsigned_abs();
+        let scaled = mantissa as u128 * denominator as u128;
+        let ordering = if exp >= 0 {
+            if exp as u32 > scaled.leading_zeros() {
+                Ordering::Less
+            } else {
+                magnitude.cmp(&(scaled << exp))
+            }
+        } else if (-exp) as u32 > magnitude.leading_zeros() {
+            Ordering::Greater
+        } else {
+            (magnitude << -exp).cmp(&scaled)
+        };
+        Some(if float_sign < 0 { ordering.reverse() } else { ordering })
+    }
+}
+
+/// Written as the `Display` string "n/d", or "n" for integers, with the sign on the numerator
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fraction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Reads anything `FromStr` accepts, a bare integer, a `[numerator, denominator]` pair or a
+/// `{"numerator": n, "denominator": d}` struct, always rejecting a zero denominator
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fraction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(FractionVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct FractionVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for FractionVisitor {
+    type Value = Fraction;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "a fraction like \"3/4\", an integer, or a numerator and denominator")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(|error| E::custom(format_args!("could not parse '{}': {}", v, error)))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Fraction::from_int(v))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        let v = i64::try_from(v).map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))?;
+        Ok(Fraction::from_int(v))
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        use serde::de::Error;
+
+        let numerator = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let denominator = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(3, &self));
+        }
+        Fraction::try_new(numerator, denominator).map_err(A::Error::custom)
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        use serde::de::Error;
+
+        let (mut numerator, mut denominator) = (None, None);
+        while let Some(key) = map.next_key::<String>()? {
+            let field = match key.as_str() {
+                "numerator" => &mut numerator,
+                "denominator" => &mut denominator,
+                _ => return Err(A::Error::unknown_field(&key, &["numerator", "denominator"])),
+            };
+            if field.is_some() {
+                return Err(A::Error::custom(format_args!("duplicate field `{}`", key)));
+            }
+            *field = Some(map.next_value()?);
+        }
+        let numerator = numerator.ok_or_else(|| A::Error::missing_field("numerator"))?;
+        let denominator = denominator.ok_or_else(|| A::Error::missing_field("denominator"))?;
+        Fraction::try_new(numerator, denominator).map_err(A::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fraction::{
+        DivisionByZero, Fraction, FractionOverflow, FromFloatError, ParseFractionError, ZeroDenominator,
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn try_new_zero_denominator() {
+        assert_eq!(Fraction::try_new(5, 0).unwrap_err(), ZeroDenominator);
+        assert_eq!(Fraction::try_new(0, 0).unwrap_err(), ZeroDenominator);
+    }
+
+    #[test]
+    fn undefined_fractions_cannot_be_created() {
+        assert_eq!(Fraction::try_new(1, 0), Err(ZeroDenominator));
+        assert_eq!(Fraction::try_new(2, 0), Err(ZeroDenominator));
+        assert_eq!("1/0".parse::<Fraction>().unwrap_err(), ParseFractionError::ZeroDenominator);
+        assert_eq!(Fraction::from(1).checked_div(Fraction::ZERO), None);
+
+        // 0/0 can't stand in for zero, but a zero numerator over anything else is exactly zero
+        assert_eq!(Fraction::try_new(0, 0), Err(ZeroDenominator));
+        assert!(Fraction::new(0, -5).is_zero());
+        assert_eq!(Fraction::new(0, -5), Fraction::ZERO);
+        assert_eq!(Fraction::new(0, -5), 0);
+    }
+
+    #[test]
+    fn try_new() {
+        let result = Fraction::try_new(5, 7).unwrap();
+        assert!(result.is_same_as(&Fraction::new(5, 7)));
+    }
+
+    #[test]
+    #[should_panic(expected = "zero denominator")]
+    fn new_zero_denominator_panics() {
+        Fraction::new(5, 0);
+    }
+
+    #[test]
+    fn reciprocal_of_zero() {
+        assert_eq!(Fraction::new(0, 5).reciprocal().unwrap_err(), DivisionByZero);
+    }
+
+    #[test]
+    fn wide_paths_match_fast_paths() {
+        let values = [
+            Fraction::new(355, 113),
+            Fraction::new(-22, -7),
+            Fraction::new(3, -4),
+            Fraction::new(i64::MAX, 6),
+            Fraction::new(i64::MIN, 3),
+        ];
+        for &a in values.iter() {
+            for &b in values.iter() {
+                assert_eq!(a.checked_add_wide(b), a.checked_add(b), "{} + {}", a, b);
+                assert_eq!(a.checked_mul_wide(b), a.checked_mul(b), "{} * {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn checked_div_by_zero() {
+        assert_eq!(Fraction::new(1, 2).checked_div(Fraction::new(0, 3)), None);
+        assert_eq!(Fraction::new(1, 2).checked_div(Fraction::new(0, 7)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "divide a fraction by zero")]
+    fn division_by_zero_panics() {
+        let _ = Fraction::new(1, 2) / Fraction::new(0, 3);
+    }
+
+    #[test]
+    fn from_string_zero_denominator() {
+        let result = Fraction::from_str("1/0");
+        assert_eq!(result.unwrap_err(), ParseFractionError::ZeroDenominator);
+    }
+
+    #[test]
+    fn is_zero() {
+        assert!(Fraction::new(0, 10).is_zero());
+    }
+
+    #[test]
+    fn is_same_as() {
+        let f1 = Fraction::new(123, 456);
+        let f2 = Fraction::new(123, 456);
+        assert!(f1.is_same_as(&f2));
+    }
+
+    #[test]
+    fn is_not_same_as() {
+        let f1 = Fraction::new(123, 456);
+        let f2 = Fraction::new(456, 123);
+        assert!(!f1.is_same_as(&f2));
+    }
+
+    #[test]
+    fn reciprocal() {
+        let f1 = Fraction::new(123, 456);
+        let result = f1.reciprocal().unwrap();
+        let expected = Fraction::new(456, 123);
+        assert!(result.is_same_as(&expected));
+
+        let result = Fraction::new(-2, 3).reciprocal().unwrap();
+        assert!(result.is_same_as(&Fraction::new(3, -2)));
+    }
+
+    #[test]
+    fn equality() {
+        let f1 = Fraction::new(6, 12);
+        let f2 = Fraction::new(1, 2);
+        assert_eq!(f1, f2);
+    }
+
+    #[test]
+    fn equality_with_int() {
+        let f1 = Fraction::new(3, 1);
+        let expected = f1.numerator();
+        assert_eq!(f1, expected);
+    }
+
+    #[test]
+    fn equality_with_zero() {
+        assert_eq!(Fraction::new(0, 1), 0);
+    }
+
+    #[test]
+    fn simplify_positive() {
+        let mut f1 = Fraction::new(8, 32);
+        f1.simplify();
+        let expected = Fraction::new(1, 4);
+        assert_eq!(f1.numerator(), expected.numerator());
+        assert_eq!(f1.denominator(), expected.denominator());
+    }
+
+    #[test]
+    fn simplify_negative() {
+        let mut f1 = Fraction::new(-3, 9);
+        f1.simplify();
+        let expected = Fraction::new(-1, 3);
+        assert_eq!(f1.numerator(), expected.numerator());
+        assert_eq!(f1.denominator(), expected.denominator());
+    }
+
+    #[test]
+    fn simplify_zero() {
+        let mut f1 = Fraction::new(0, 1);
+        f1.simplify();
+        assert_eq!(f1, 0);
+    }
+
+    #[test]
+    fn simplify_zero_with_other_denominator() {
+        let mut f1 = Fraction::new(0, 4) + Fraction::new(0, 4);
+        f1.simplify();
+        assert_eq!(f1, 0);
+    }
+
+    #[test]
+    fn simplify_double_negative() {
+        let mut f1 = Fraction::new(-5, -25);
+        f1.simplify();
+        let expected = Fraction::new(1, 5);
+        assert_eq!(f1.numerator(), expected.numerator());
+        assert_eq!(f1.denominator(), expected.denominator());
+    }
+
+    #[test]
+    fn simplify_i64_min_over_minus_one() {
+        assert_eq!(Fraction::new(i64::MIN, -1).checked_simplified(), None);
+        let f = Fraction::new(i64::MIN, -2).checked_simplified().unwrap();
+        assert!(f.is_same_as(&Fraction::from_int(1 << 62)));
+        assert!(Fraction::new(i64::MIN, i64::MIN).clone_simplified().is_same_as(&Fraction::from(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "simplify a fraction with overflow")]
+    fn simplify_overflow_panics() {
+        Fraction::new(i64::MIN, -1).simplify();
+    }
+
+    #[test]
+    fn negate_i64_min_numerator() {
+        let f = -Fraction::new(i64::MIN, 3);
+        assert!(f.is_same_as(&Fraction::new(i64::MIN, -3)));
+        assert!(f > 0);
+        assert!((-Fraction::from_int(i64::MIN)).is_same_as(&Fraction::new(i64::MIN, -1)));
+        assert!((-Fraction::new(i64::MIN, i64::MIN)).is_same_as(&Fraction::from(-1)));
+    }
+
+    #[test]
+    fn checked_neg() {
+        assert!(Fraction::new(3, 4).checked_neg().unwrap().is_same_as(&Fraction::new(-3, 4)));
+        assert!(Fraction::new(3, -4).checked_neg().unwrap().is_same_as(&Fraction::new(-3, -4)));
+        assert!(Fraction::new(i64::MIN, 3).checked_neg().unwrap().is_same_as(&Fraction::new(i64::MIN, -3)));
+        assert!(Fraction::new(i64::MIN, i64::MIN).checked_neg().unwrap().is_same_as(&Fraction::from(-1)));
+    }
+
+    #[test]
+    fn addition_with_common_denominator() {
+        let f1 = Fraction::new(1, 5);
+        let f2 = Fraction::new(3, 5);
+        let result = f1 + f2;
+        let expected = Fraction::new(4, 5);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn addition_when_denominators_multiply_beyond_i64() {
+        // 6 * 2^58 times 10 * 2^58 doesn't fit in an i64 but their lcm 30 * 2^58 does
+        let f1 = Fraction::new(1, 6 << 58);
+        let f2 = Fraction::new(1, 10 << 58);
+        assert!((f1 + f2).is_same_as(&Fraction::new(1, 15 << 56)));
+        assert!((f1 - f2).is_same_as(&Fraction::new(1, 15 << 58)));
+        assert!((f1 + Fraction::new(1, -(10 << 58))).is_same_as(&Fraction::new(1, 15 << 58)));
+    }
+
+    #[test]
+    fn addition_without_common_denominator() {
+        let f1 = Fraction::new(1, 3);
+        let f2 = Fraction::new(1, 2);
+        let result = f1 + f2;
+        let expected = Fraction::new(5, 6);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn addition_with_two_negatives() {
+        let f1 = Fraction::new(-2, 3);
+        let f2 = Fraction::new(-4, 8);
+        let result = f1 + f2;
+        let expected = Fraction::new(-7, 6);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn addition_with_one_negative_first() {
+        let f1 = Fraction::new(-7, 14);
+        let f2 = Fraction::new(1, 1);
+        let result = f1 + f2;
+        let expected = Fraction::new(1, 2);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn addition_with_one_negative_last() {
+        let f1 = Fraction::new(3, 5);
+        let f2 = Fraction::new(-2, 5);
+        let result = f1 + f2;
+        let expected = Fraction::new(1, 5);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn addition_of_three_fractions() {
+        let f1 = Fraction::new(5, 4);
+        let f2 = Fraction::new(3, 4);
+        let f3 = Fraction::new(8, 4);
+        let result = f1 + f2 + f3;
+        let expected = 4;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn negative() {
+        let f1 = Fraction::new(4, 3);
+        let result = -f1;
+        let expected = Fraction::new(-4, 3);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn subtraction() {
+        let f1 = Fraction::new(6, 7);
+        let f2 = Fraction::new(2, 7);
+        let result = f1 - f2;
+        let expected = Fraction::new(4, 7);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn from_string() {
+        let result = Fraction::from_str("6/18").unwrap();
+        let expected = Fraction::new(1, 3);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn from_string_to_whole_number() {
+        let result = Fraction::from_str("8/4").unwrap();
+        let expected = 2;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn multiplication() {
+        let f1 = Fraction::new(5, 8);
+        let f2 = Fraction::new(6,12);
+        let result = f1 * f2;
+        let expected = Fraction::new(5, 16);
+        assert!(result.is_same_as(&expected));
+    }
+
+    #[test]
+    fn multiplication_to_whole_number() {
+        let f1 = Fraction::new(9, 3);
+        let f2 = Fraction::new(1,3);
+        let result = f1 * f2;
+        let expected = 1;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn division() {
+        let f1 = Fraction::new(1, 6);
+        let f2 = Fraction::new(1, 3);
+        let result = f1 / f2;
+        let expected = Fraction::new(1, 2);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn division_to_whole_number() {
+        let f1 = Fraction::new(1, 2);
+        let f2 = Fraction::new(1, 4);
+        let result = f1 / f2;
+        let expected = 2;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn complex_equation1() {
+        let f1 = Fraction::new(1, 2);
+        let f2 = Fraction::new(1, 4);
+        let result = (f1 + f2 - f2) / f2 * -f1;
+        let expected = -1;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn checked_add() {
+        let result = Fraction::new(1, 3).checked_add(Fraction::new(1, 2));
+        assert_eq!(result, Some(Fraction::new(5, 6)));
+    }
+
+    #[test]
+    fn checked_add_overflow() {
+        // Wraps around to a garbage answer in release builds without the checked ops
+        let result = Fraction::new(i64::MAX, 2).checked_add(Fraction::new(i64::MAX, 3));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn checked_add_overflow_with_common_denominator() {
+        let result = Fraction::new(i64::MAX, 7).checked_add(Fraction::new(1, 7));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn checked_sub() {
+        let result = Fraction::new(6, 7).checked_sub(Fraction::new(2, 7));
+        assert_eq!(result, Some(Fraction::new(4, 7)));
+    }
+
+    #[test]
+    fn checked_sub_overflow() {
+        assert_eq!(Fraction::new(1, 2).checked_sub(Fraction::new(i64::MIN, 2)), None);
+        assert_eq!(Fraction::new(i64::MIN, 1).checked_sub(Fraction::new(1, 1)), None);
+    }
+
+    #[test]
+    fn checked_mul() {
+        let result = Fraction::new(5, 8).checked_mul(Fraction::new(6, 12));
+        assert_eq!(result, Some(Fraction::new(30, 96)));
+    }
+
+    #[test]
+    fn checked_mul_overflow() {
+        let result = Fraction::new(1 << 32, 3).checked_mul(Fraction::new(1 << 32, 5));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn checked_div() {
+        let result = Fraction::new(1, 6).checked_div(Fraction::new(1, 3));
+        assert_eq!(result, Some(Fraction::new(1, 2)));
+    }
+
+    #[test]
+    fn checked_div_overflow() {
+        let result = Fraction::new(1 << 32, 3).checked_div(Fraction::new(5, 1 << 32));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn addition_overflow_panics() {
+        let _ = Fraction::new(i64::MAX, 2) + Fraction::new(i64::MAX, 3);
+    }
+
+    #[test]
+    fn checked_mul_reduces_before_narrowing() {
+        let result = Fraction::new(4_000_000_000, 3).checked_mul(Fraction::new(3_000_000_000, 4_000_000_000));
+        assert_eq!(result, Some(Fraction::new(1_000_000_000, 1)));
+
+        let result = Fraction::new(1 << 40, 3).checked_mul(Fraction::new(5, 1 << 40));
+        assert_eq!(result, Some(Fraction::new(5, 3)));
+    }
+
+    #[test]
+    fn checked_add_reduces_before_narrowing() {
+        let result = Fraction::new(1, 3_037_000_500).checked_add(Fraction::new(2, 6_074_001_000));
+        assert_eq!(result, Some(Fraction::new(2, 3_037_000_500)));
+    }
+
+    #[test]
+    fn checked_div_reduces_before_narrowing() {
+        let f1 = Fraction::new(4_000_000_000, 3_000_000_000);
+        let result = f1.checked_div(f1);
+        assert_eq!(result, Some(Fraction::new(1, 1)));
+    }
+
+    #[test]
+    fn multiplication_beyond_i32() {
+        // Both the numerator and denominator of the result overflow an i32
+        let result = Fraction::new(100_000, 7) * Fraction::new(100_000, 11);
+        let expected = Fraction::new(10_000_000_000, 77);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn equality_with_i64() {
+        let f1 = Fraction::new(10_000_000_000, 2);
+        assert_eq!(f1, 5_000_000_000i64);
+        assert_eq!(5_000_000_000i64, f1);
+    }
+
+    #[test]
+    fn arithmetic_results_are_simplified() {
+        assert!((Fraction::new(1, 6) + Fraction::new(1, 3)).is_same_as(&Fraction::new(1, 2)));
+        assert!((Fraction::new(3, 4) - Fraction::new(1, 4)).is_same_as(&Fraction::new(1, 2)));
+        assert!((Fraction::new(2, 3) * Fraction::new(3, 4)).is_same_as(&Fraction::new(1, 2)));
+        assert!((Fraction::new(1, 4) / Fraction::new(1, 2)).is_same_as(&Fraction::new(1, 2)));
+    }
+
+    #[test]
+    fn arithmetic_results_have_positive_denominator() {
+        let result = Fraction::new(1, -3) * Fraction::new(1, 5);
+        assert!(result.is_same_as(&Fraction::new(-1, 15)));
+
+        let result = Fraction::new(1, 2) / Fraction::new(-1, 3);
+        assert!(result.is_same_as(&Fraction::new(-3, 2)));
+    }
+
+    #[test]
+    fn chained_multiplication() {
+        let f1 = Fraction::new(2, 4);
+        let mut result = f1;
+        for _ in 0..9 {
+            result = result.checked_mul(f1).unwrap();
+        }
+        assert!(result.is_same_as(&Fraction::new(1, 1024)));
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Fraction::new(2, 3) < Fraction::new(3, 4));
+        assert!(Fraction::new(3, 4) > Fraction::new(2, 3));
+        assert!(Fraction::new(-3, 4) < Fraction::new(-2, 3));
+    }
+
+    #[test]
+    fn ordering_with_mixed_signs() {
+        assert!(Fraction::new(-1, 2) < Fraction::new(1, 3));
+        assert!(Fraction::new(1, -2) < Fraction::new(1, 3));
+        assert!(Fraction::new(-1, -2) > Fraction::new(1, 3));
+        assert!(Fraction::new(0, -5) > Fraction::new(-1, 3));
+    }
+
+    #[test]
+    fn ordering_of_equivalent_fractions() {
+        assert_eq!(Fraction::new(2, 4).cmp(&Fraction::new(1, 2)), std::cmp::Ordering::Equal);
+        assert_eq!(Fraction::new(-2, -4).cmp(&Fraction::new(1, 2)), std::cmp::Ordering::Equal);
+        assert!(Fraction::new(2, 4) <= Fraction::new(1, 2));
+        assert!(Fraction::new(2, 4) >= Fraction::new(1, 2));
+    }
+
+    #[test]
+    fn ordering_near_limits() {
+        // Cross multiplying these overflows an i64
+        assert!(Fraction::new(i64::MAX, i64::MAX - 1) < Fraction::new(i64::MAX - 1, i64::MAX - 2));
+        assert!(Fraction::new(i64::MIN, 1) < Fraction::new(i64::MAX, -1));
+        assert!(Fraction::new(i64::MAX, 1) > Fraction::new(i64::MAX - 1, 1));
+    }
+
+    #[test]
+    fn ordering_with_int() {
+        assert!(Fraction::new(7, 2) > 3);
+        assert!(Fraction::new(7, 2) < 4);
+        assert!(Fraction::new(-7, 2) < -3);
+        assert!(Fraction::new(8, 2) <= 4);
+    }
+
+    #[test]
+    fn sort() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let expected = vec![
+            Fraction::new(-3, 2),
+            Fraction::new(-1, 3),
+            Fraction::new(0, 1),
+            Fraction::new(1, 4),
+            Fraction::new(1, 3),
+            Fraction::new(1, 2),
+            Fraction::new(5, 3),
+        ];
+        let mut fractions = expected.clone();
+        fractions.shuffle(&mut StdRng::seed_from_u64(385));
+        fractions.sort();
+        assert!(fractions.iter().zip(&expected).all(|(f1, f2)| f1.is_same_as(f2)));
+    }
+
+    fn hash_of(f: &Fraction) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        f.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn hash_set_of_equivalent_fractions() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Fraction::new(1, 2));
+        set.insert(Fraction::new(2, 4));
+        set.insert(Fraction::new(3, 6));
+        set.insert(Fraction::new(-4, -8));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn hash_with_sign_in_denominator() {
+        assert_eq!(hash_of(&Fraction::new(1, -2)), hash_of(&Fraction::new(-1, 2)));
+        assert_eq!(hash_of(&Fraction::new(0, -5)), hash_of(&Fraction::new(0, 1)));
+        assert_ne!(hash_of(&Fraction::new(1, 2)), hash_of(&Fraction::new(-1, 2)));
+    }
+
+    #[test]
+    fn equal_fractions_hash_equally() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(386);
+        for _ in 0..10_000 {
+            let numerator = rng.gen_range(-1000..=1000);
+            let denominator = rng.gen_range(1..=1000) * if rng.gen() { 1 } else { -1 };
+            let factor = rng.gen_range(1..=1000) * if rng.gen() { 1 } else { -1 };
+            let f1 = Fraction::new(numerator, denominator);
+            let f2 = Fraction::new(numerator * factor, denominator * factor);
+            assert_eq!(f1, f2);
+            assert_eq!(hash_of(&f1), hash_of(&f2), "{} and {}", f1, f2);
+        }
+    }
+
+    #[test]
+    fn equality_beyond_i64_products() {
+        let f1 = Fraction::new(i64::MAX - 1, i64::MAX);
+        let f2 = Fraction::new(-(i64::MAX - 1), -i64::MAX);
+        assert_eq!(f1, f2);
+        assert_ne!(f1, Fraction::new(i64::MAX - 2, i64::MAX - 1));
+        assert_eq!(Fraction::new(i64::MIN, i64::MIN), Fraction::new(i64::MAX, i64::MAX));
+        assert_eq!(Fraction::new(i64::MIN, -2), 1i64 << 62);
+        assert_ne!(Fraction::new(i64::MIN, 1), Fraction::new(i64::MAX, -1));
+    }
+
+    #[test]
+    fn equality_matches_simplifying() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(418);
+        for _ in 0..10_000 {
+            let f1 = Fraction::new(rng.gen_range(-50..=50), rng.gen_range(1..=50) * if rng.gen() { 1 } else { -1 });
+            let f2 = Fraction::new(rng.gen_range(-50..=50), rng.gen_range(1..=50) * if rng.gen() { 1 } else { -1 });
+            let simplified_eq = f1.clone_simplified().is_same_as(&f2.clone_simplified());
+            assert_eq!(f1 == f2, simplified_eq, "{} and {}", f1, f2);
+        }
+    }
+
+    #[test]
+    fn gcd_of_fractions() {
+        assert!(Fraction::new(1, 2).gcd(&Fraction::new(1, 3)).is_same_as(&Fraction::new(1, 6)));
+        assert!(Fraction::new(2, 3).gcd(&Fraction::new(4, 9)).is_same_as(&Fraction::new(2, 9)));
+        assert!(Fraction::new(-6, 8).gcd(&Fraction::new(3, -8)).is_same_as(&Fraction::new(3, 8)));
+        assert!(Fraction::ZERO.gcd(&Fraction::new(5, 6)).is_same_as(&Fraction::new(5, 6)));
+        assert!(Fraction::from(12).gcd(&Fraction::from(18)).is_same_as(&Fraction::from(6)));
+    }
+
+    #[test]
+    fn lcm_of_fractions() {
+        assert!(Fraction::new(1, 2).lcm(&Fraction::new(1, 3)).is_same_as(&Fraction::from(1)));
+        assert!(Fraction::new(2, 3).lcm(&Fraction::new(4, 9)).is_same_as(&Fraction::new(4, 3)));
+        assert!(Fraction::new(-3, 4).lcm(&Fraction::new(5, 6)).is_same_as(&Fraction::new(15, 2)));
+        assert!(Fraction::ZERO.lcm(&Fraction::new(5, 6)).is_same_as(&Fraction::ZERO));
+        assert!(Fraction::from(4).lcm(&Fraction::from(6)).is_same_as(&Fraction::from(12)));
+    }
+
+    #[test]
+    fn gcd_and_lcm_of_fractions_overflow() {
+        let (f1, f2) = (Fraction::new(1, i64::MAX), Fraction::new(1, i64::MAX - 1));
+        assert_eq!(f1.checked_gcd(&f2), None);
+        assert_eq!(Fraction::from(i64::MAX).checked_lcm(&Fraction::from(i64::MAX - 1)), None);
+        assert_eq!(f1.checked_lcm(&f2), Some(Fraction::from(1)));
+    }
+
+    #[test]
+    fn round_to_sixteenths() {
+        assert!(Fraction::new(5, 12).round_to(16).is_same_as(&Fraction::new(7, 16)));
+        assert!(Fraction::new(-5, 12).round_to(16).is_same_as(&Fraction::new(-7, 16)));
+        assert!(Fraction::new(5, -12).round_to(16).is_same_as(&Fraction::new(-7, 16)));
+        assert!(Fraction::new(1, 100).round_to(16).is_same_as(&Fraction::new(0, 16)));
+    }
+
+    #[test]
+    fn round_to_ties_away_from_zero() {
+        assert!(Fraction::new(1, 32).round_to(16).is_same_as(&Fraction::new(1, 16)));
+        assert!(Fraction::new(-1, 32).round_to(16).is_same_as(&Fraction::new(-1, 16)));
+        assert!(Fraction::new(3, 16).round_to(8).is_same_as(&Fraction::new(2, 8)));
+    }
+
+    #[test]
+    fn round_to_exact_multiples() {
+        assert!(Fraction::new(3, 8).round_to(16).is_same_as(&Fraction::new(6, 16)));
+        assert!(Fraction::new(-3, 8).floor_to(16).is_same_as(&Fraction::new(-6, 16)));
+        assert!(Fraction::from(2).ceil_to(8).is_same_as(&Fraction::new(16, 8)));
+    }
+
+    #[test]
+    fn floor_to_and_ceil_to() {
+        assert!(Fraction::new(5, 12).floor_to(16).is_same_as(&Fraction::new(6, 16)));
+        assert!(Fraction::new(5, 12).ceil_to(16).is_same_as(&Fraction::new(7, 16)));
+        assert!(Fraction::new(-5, 12).floor_to(16).is_same_as(&Fraction::new(-7, 16)));
+        assert!(Fraction::new(-5, 12).ceil_to(16).is_same_as(&Fraction::new(-6, 16)));
+    }
+
+    #[test]
+    #[should_panic(expected = "less than one")]
+    fn round_to_zero_denominator_panics() {
+        Fraction::new(1, 2).round_to(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "less than one")]
+    fn round_to_negative_denominator_panics() {
+        Fraction::new(1, 2).floor_to(-16);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn round_to_overflow_panics() {
+        Fraction::from(i64::MAX).ceil_to(2);
+    }
+
+    #[test]
+    fn arithmetic_promotes_when_i64_intermediates_overflow() {
+        let half_max = Fraction::new(i64::MAX, 2);
+        assert!(half_max.checked_add(half_max).unwrap().is_same_as(&Fraction::from(i64::MAX)));
+        assert!(Fraction::from(i64::MIN).checked_sub(Fraction::new(i64::MIN, 2)).unwrap()
+            .is_same_as(&Fraction::new(i64::MIN / 2, 1)));
+        let product = Fraction::new(1 << 62, 3).checked_mul(Fraction::new(3, 1 << 61)).unwrap();
+        assert!(product.is_same_as(&Fraction::from(2)));
+        let quotient = Fraction::new(i64::MAX, 2).checked_div(Fraction::new(i64::MAX, 4)).unwrap();
+        assert!(quotient.is_same_as(&Fraction::from(2)));
+    }
+
+    #[test]
+    fn arithmetic_overflows_when_the_reduced_result_does_not_fit() {
+        assert_eq!(Fraction::from(i64::MAX).checked_add(Fraction::from(1)), None);
+        assert_eq!(Fraction::from(i64::MIN).checked_sub(Fraction::new(1, 2)), None);
+        assert_eq!(Fraction::new(i64::MAX, 3).checked_mul(Fraction::new(5, 2)), None);
+        assert_eq!(Fraction::new(1, i64::MAX).checked_div(Fraction::from(2)), None);
+    }
+
+    #[test]
+    fn small_results_keep_canonical_signs() {
+        let sum = Fraction::new(1, -2).checked_add(Fraction::new(1, -3)).unwrap();
+        assert!(sum.is_same_as(&Fraction::new(-5, 6)));
+        let product = Fraction::new(i64::MIN, -1).checked_mul(Fraction::new(1, 2)).unwrap();
+        assert!(product.is_same_as(&Fraction::from_int(1 << 62)));
+        assert_eq!(Fraction::new(i64::MIN, -1).checked_mul(Fraction::from(1)), None);
+        let product = Fraction::new(i64::MIN, i64::MIN).checked_mul(Fraction::from(3)).unwrap();
+        assert!(product.is_same_as(&Fraction::from(3)));
+        let sum = Fraction::new(0, i64::MIN).checked_add(Fraction::ZERO).unwrap();
+        assert!(sum.is_same_as(&Fraction::ZERO));
+    }
+
+    #[test]
+    fn assigning_operators() {
+        let mut f1 = Fraction::new(1, 2);
+        f1 += Fraction::new(1, 3);
+        assert!(f1.is_same_as(&Fraction::new(5, 6)));
+        f1 -= Fraction::new(1, 6);
+        assert!(f1.is_same_as(&Fraction::new(2, 3)));
+        f1 *= Fraction::new(3, 4);
+        assert!(f1.is_same_as(&Fraction::new(1, 2)));
+        f1 /= Fraction::new(1, 4);
+        assert!(f1.is_same_as(&Fraction::new(2, 1)));
+    }
+
+    #[test]
+    fn assigning_operators_with_int() {
+        let mut f1 = Fraction::new(1, 2);
+        f1 += 1;
+        assert!(f1.is_same_as(&Fraction::new(3, 2)));
+        f1 -= 2;
+        assert!(f1.is_same_as(&Fraction::new(-1, 2)));
+        f1 *= 4;
+        assert!(f1.is_same_as(&Fraction::new(-2, 1)));
+        f1 /= -6;
+        assert!(f1.is_same_as(&Fraction::new(1, 3)));
+    }
+
+    #[test]
+    fn harmonic_series_with_add_assign() {
+        let mut total = Fraction::ZERO;
+        for n in 1..=20 {
+            total += Fraction::new(1, n);
+        }
+        let expected = (1..=20).fold(Fraction::ZERO, |sum, n| sum + Fraction::new(1, n));
+        assert!(total.is_same_as(&expected));
+        assert!(total.is_same_as(&Fraction::new(55_835_135, 15_519_504)));
+    }
+
+    #[test]
+    #[should_panic(expected = "divide a fraction by zero")]
+    fn div_assign_by_zero_panics() {
+        let mut f1 = Fraction::new(1, 2);
+        f1 /= 0;
+    }
+
+    #[test]
+    fn arithmetic_with_int() {
+        let f1 = Fraction::new(3, 4);
+        let cases: [(Fraction, Fraction); 16] = [
+            (f1 + 2, Fraction::new(11, 4)),
+            (2 + f1, Fraction::new(11, 4)),
+            (f1 + -2, Fraction::new(-5, 4)),
+            (-2 + f1, Fraction::new(-5, 4)),
+            (f1 - 2, Fraction::new(-5, 4)),
+            (2 - f1, Fraction::new(5, 4)),
+            (f1 - -2, Fraction::new(11, 4)),
+            (-2 - f1, Fraction::new(-11, 4)),
+            (f1 * 2, Fraction::new(3, 2)),
+            (2 * f1, Fraction::new(3, 2)),
+            (f1 * -2, Fraction::new(-3, 2)),
+            (-2 * f1, Fraction::new(-3, 2)),
+            (f1 / 2, Fraction::new(3, 8)),
+            (2 / f1, Fraction::new(8, 3)),
+            (f1 / -2, Fraction::new(-3, 8)),
+            (-2 / f1, Fraction::new(-8, 3)),
+        ];
+        for (i, (result, expected)) in cases.iter().enumerate() {
+            assert!(result.is_same_as(expected), "case {}: {} != {}", i, result, expected);
+        }
+    }
+
+    #[test]
+    fn arithmetic_with_i64() {
+        let f1 = Fraction::new(1, 3);
+        assert_eq!(f1 + 5_000_000_000i64, Fraction::new(15_000_000_001, 3));
+        assert_eq!(5_000_000_000i64 - f1, Fraction::new(14_999_999_999, 3));
+        assert_eq!(f1 * 6_000_000_000i64, 2_000_000_000);
+        assert_eq!(6_000_000_000i64 / f1, 18_000_000_000i64);
+    }
+
+    #[test]
+    #[should_panic(expected = "divide a fraction by zero")]
+    fn int_divided_by_zero_fraction_panics() {
+        let _ = 2 / Fraction::new(0, 3);
+    }
+
+    #[test]
+    fn arithmetic_with_references() {
+        let f1 = Fraction::new(2, 3);
+        let f2 = Fraction::new(3, 4);
+        let (r1, r2) = (&f1, &f2);
+        let by_value = [f1 + f2, f1 - f2, f1 * f2, f1 / f2];
+        let by_refs = [r1 + r2, r1 - r2, r1 * r2, r1 / r2];
+        let ref_value = [r1 + f2, r1 - f2, r1 * f2, r1 / f2];
+        let value_ref = [f1 + r2, f1 - r2, f1 * r2, f1 / r2];
+        for i in 0..by_value.len() {
+            assert!(by_refs[i].is_same_as(&by_value[i]));
+            assert!(ref_value[i].is_same_as(&by_value[i]));
+            assert!(value_ref[i].is_same_as(&by_value[i]));
+        }
+    }
+
+    #[test]
+    fn negative_reference() {
+        let f1 = Fraction::new(4, 3);
+        assert!((-&f1).is_same_as(&Fraction::new(-4, 3)));
+    }
+
+    #[test]
+    fn fold_over_references() {
+        let fractions = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+        let result = fractions.iter().fold(Fraction::ZERO, |sum, f| sum + f);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn sum() {
+        let fractions: Vec<Fraction> = (1..=10).map(|n| Fraction::new(1, n)).collect();
+        let expected = Fraction::new(7381, 2520);
+        assert!(fractions.iter().sum::<Fraction>().is_same_as(&expected));
+        assert!(fractions.into_iter().sum::<Fraction>().is_same_as(&expected));
+    }
+
+    #[test]
+    fn sum_of_nothing() {
+        let fractions: Vec<Fraction> = Vec::new();
+        assert_eq!(fractions.iter().sum::<Fraction>(), 0);
+    }
+
+    #[test]
+    fn product() {
+        let fractions = vec![Fraction::new(1, 2); 15];
+        let expected = Fraction::new(1, 32768);
+        assert!(fractions.iter().product::<Fraction>().is_same_as(&expected));
+        assert!(fractions.into_iter().product::<Fraction>().is_same_as(&expected));
+    }
+
+    #[test]
+    fn try_sum_overflow() {
+        let fractions = [Fraction::new(i64::MAX, 1), Fraction::new(1, 1)];
+        assert_eq!(Fraction::try_sum(fractions.iter()), Err(FractionOverflow));
+        assert_eq!(Fraction::try_sum(fractions[..1].iter()), Ok(Fraction::new(i64::MAX, 1)));
+    }
+
+    #[test]
+    fn try_product_overflow() {
+        let fractions = vec![Fraction::new(1, 1 << 32); 2];
+        assert_eq!(Fraction::try_product(fractions), Err(FractionOverflow));
+    }
+
+    #[test]
+    fn constants_are_canonical() {
+        assert!(Fraction::ZERO.is_same_as(&Fraction::new(0, 1)));
+        assert!(Fraction::ONE.is_same_as(&Fraction::new(1, 1)));
+    }
+
+    #[test]
+    fn identities() {
+        let samples = [
+            Fraction::new(1, 2),
+            Fraction::new(-7, 3),
+            Fraction::new(6, -8),
+            Fraction::new(0, 5),
+            Fraction::new(i64::MAX, 1),
+        ];
+        for f1 in samples.iter() {
+            assert_eq!(Fraction::ZERO + *f1, *f1);
+            assert_eq!(*f1 + Fraction::ZERO, *f1);
+            assert_eq!(Fraction::ONE * *f1, *f1);
+            assert_eq!(*f1 * Fraction::ONE, *f1);
+        }
+    }
+
+    #[test]
+    fn const_construction() {
+        const HALF: Fraction = Fraction::new(1, 2);
+        const SEVEN: Fraction = Fraction::from_int(7);
+        assert_eq!(HALF * SEVEN, Fraction::new(7, 2));
+    }
+
+    #[test]
+    fn default_inside_derived_struct() {
+        #[derive(Default)]
+        struct Recipe {
+            flour: Fraction,
+            sugar: Fraction,
+        }
+
+        let recipe = Recipe::default();
+        assert!(recipe.flour.is_same_as(&Fraction::ZERO));
+        assert!(recipe.sugar.is_same_as(&Fraction::ZERO));
+    }
+
+    #[test]
+    fn try_from_tuple() {
+        use std::convert::TryFrom;
+
+        assert!(Fraction::try_from((3, 4)).unwrap().is_same_as(&Fraction::new(3, 4)));
+        assert!(Fraction::try_from((3, -4)).unwrap().is_same_as(&Fraction::new(-3, 4)));
+        assert!(Fraction::try_from((-3, -4)).unwrap().is_same_as(&Fraction::new(3, 4)));
+        assert!(Fraction::try_from((i32::MIN, -1)).unwrap().is_same_as(&Fraction::new(2_147_483_648, 1)));
+        assert_eq!(Fraction::try_from((3, 0)).unwrap_err(), ZeroDenominator);
+    }
+
+    #[test]
+    fn from_small_ints() {
+        assert!(Fraction::from(200u8).is_same_as(&Fraction::new(200, 1)));
+        assert!(Fraction::from(-300i16).is_same_as(&Fraction::new(-300, 1)));
+        assert!(Fraction::from(u16::MAX).is_same_as(&Fraction::new(65535, 1)));
+    }
+
+    #[test]
+    fn predicates() {
+        // (fraction, is_integer, is_proper, is_negative, is_positive, signum)
+        let cases = [
+            (Fraction::new(1, 2), false, true, false, true, 1),
+            (Fraction::new(7, 3), false, false, false, true, 1),
+            (Fraction::new(-1, 2), false, true, true, false, -1),
+            (Fraction::new(1, -2), false, true, true, false, -1),
+            (Fraction::new(-7, 3), false, false, true, false, -1),
+            (Fraction::new(0, 5), true, true, false, false, 0),
+            (Fraction::new(0, -5), true, true, false, false, 0),
+            (Fraction::new(4, -2), true, false, true, false, -1),
+            (Fraction::new(-3, -9), false, true, false, true, 1),
+            (Fraction::new(-9, -3), true, false, false, true, 1),
+            (Fraction::new(5, 5), true, false, false, true, 1),
+            (Fraction::new(6, 1), true, false, false, true, 1),
+            (Fraction::new(i64::MIN, -1), true, false, false, true, 1),
+        ];
+        for (f1, is_integer, is_proper, is_negative, is_positive, signum) in cases.iter() {
+            assert_eq!(f1.is_integer(), *is_integer, "is_integer of {}", f1);
+            assert_eq!(f1.is_proper(), *is_proper, "is_proper of {}", f1);
+            assert_eq!(f1.is_negative(), *is_negative, "is_negative of {}", f1);
+            assert_eq!(f1.is_positive(), *is_positive, "is_positive of {}", f1);
+            assert_eq!(f1.signum(), *signum, "signum of {}", f1);
+        }
+    }
+
+    #[test]
+    fn predicates_do_not_simplify() {
+        let f1 = Fraction::new(4, -2);
+        assert!(f1.is_integer());
+        assert!(f1.is_same_as(&Fraction::new(4, -2)));
+    }
+
+    #[test]
+    fn abs() {
+        assert!(Fraction::new(3, 4).abs().is_same_as(&Fraction::new(3, 4)));
+        assert!(Fraction::new(-3, 4).abs().is_same_as(&Fraction::new(3, 4)));
+        assert!(Fraction::new(3, -4).abs().is_same_as(&Fraction::new(3, 4)));
+        assert!(Fraction::new(-3, -4).abs().is_same_as(&Fraction::new(3, 4)));
+    }
+
+    #[test]
+    fn abs_does_not_simplify() {
+        assert!(Fraction::new(-6, 8).abs().is_same_as(&Fraction::new(6, 8)));
+    }
+
+    #[test]
+    fn abs_of_zero() {
+        assert!(Fraction::new(0, -5).abs().is_same_as(&Fraction::new(0, 5)));
+    }
+
+    #[test]
+    fn abs_compares_with_ordering() {
+        assert!(Fraction::new(-1, 2).abs() <= Fraction::new(3, -4).abs());
+    }
+
+    #[test]
+    fn checked_abs_of_min() {
+        assert_eq!(Fraction::new(i64::MIN, 1).checked_abs(), None);
+        assert_eq!(Fraction::new(1, i64::MIN).checked_abs(), None);
+
+        // Simplifying makes these fit
+        let result = Fraction::new(i64::MIN, 2).checked_abs().unwrap();
+        assert!(result.is_same_as(&Fraction::new(1 << 62, 1)));
+        let result = Fraction::new(i64::MIN, i64::MIN).checked_abs().unwrap();
+        assert!(result.is_same_as(&Fraction::ONE));
+    }
+
+    #[test]
+    #[should_panic(expected = "absolute value")]
+    fn abs_of_min_panics() {
+        Fraction::new(i64::MIN, 1).abs();
+    }
+
+    #[test]
+    fn rounding() {
+        // (numerator, denominator, floor, ceil, trunc, round)
+        let cases = [
+            (7, 2, 3, 4, 3, 4),
+            (-7, 2, -4, -3, -3, -4),
+            (7, -2, -4, -3, -3, -4),
+            (-7, -2, 3, 4, 3, 4),
+            (5, 2, 2, 3, 2, 3),
+            (-5, 2, -3, -2, -2, -3),
+            (5, -2, -3, -2, -2, -3),
+            (-5, -2, 2, 3, 2, 3),
+            (7, 3, 2, 3, 2, 2),
+            (-7, 3, -3, -2, -2, -2),
+            (8, 3, 2, 3, 2, 3),
+            (-8, 3, -3, -2, -2, -3),
+            (1, 3, 0, 1, 0, 0),
+            (-1, 3, -1, 0, 0, 0),
+            (6, 3, 2, 2, 2, 2),
+            (-6, 3, -2, -2, -2, -2),
+            (6, -3, -2, -2, -2, -2),
+            (0, 5, 0, 0, 0, 0),
+            (0, -5, 0, 0, 0, 0),
+        ];
+        for (n, d, floor, ceil, trunc, round) in cases.iter() {
+            let f1 = Fraction::new(*n, *d);
+            assert_eq!(f1.floor(), *floor, "floor of {}", f1);
+            assert_eq!(f1.ceil(), *ceil, "ceil of {}", f1);
+            assert_eq!(f1.trunc(), *trunc, "trunc of {}", f1);
+            assert_eq!(f1.round(), *round, "round of {}", f1);
+        }
+    }
+
+    #[test]
+    fn rounding_near_limits() {
+        assert_eq!(Fraction::new(i64::MIN, 1).floor(), i64::MIN);
+        assert_eq!(Fraction::new(i64::MAX, 2).round(), (i64::MAX / 2) + 1);
+        assert_eq!(Fraction::new(i64::MIN, 3).ceil(), i64::MIN / 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "round a fraction with overflow")]
+    fn rounding_overflow_panics() {
+        Fraction::new(i64::MIN, -1).trunc();
+    }
+
+    #[test]
+    fn fract() {
+        assert!(Fraction::new(7, 2).fract().is_same_as(&Fraction::new(1, 2)));
+        assert!(Fraction::new(-7, 2).fract().is_same_as(&Fraction::new(-1, 2)));
+        assert!(Fraction::new(7, -2).fract().is_same_as(&Fraction::new(-1, 2)));
+        assert!(Fraction::new(6, 3).fract().is_same_as(&Fraction::ZERO));
+    }
+
+    #[test]
+    fn trunc_plus_fract() {
+        for n in -12..=12 {
+            for d in [-5, -4, -3, -2, -1, 1, 2, 3, 4, 5].iter() {
+                let f1 = Fraction::new(n, *d);
+                assert_eq!(f1.trunc() + f1.fract(), f1, "{}", f1);
+            }
+        }
+    }
+
+    #[test]
+    fn pow() {
+        assert!(Fraction::new(2, 3).pow(3).is_same_as(&Fraction::new(8, 27)));
+        assert!(Fraction::new(-2, 3).pow(3).is_same_as(&Fraction::new(-8, 27)));
+        assert!(Fraction::new(-2, 3).pow(2).is_same_as(&Fraction::new(4, 9)));
+        assert!(Fraction::new(4, 6).pow(2).is_same_as(&Fraction::new(4, 9)));
+    }
+
+    #[test]
+    fn pow_zero_exponent() {
+        assert!(Fraction::new(2, 3).pow(0).is_same_as(&Fraction::ONE));
+        assert!(Fraction::ZERO.pow(0).is_same_as(&Fraction::ONE));
+    }
+
+    #[test]
+    fn pow_negative_exponent() {
+        assert!(Fraction::new(2, 3).pow(-2).is_same_as(&Fraction::new(9, 4)));
+        assert!(Fraction::new(-2, 3).pow(-3).is_same_as(&Fraction::new(-27, 8)));
+    }
+
+    #[test]
+    fn pow_of_zero() {
+        assert!(Fraction::ZERO.pow(5).is_same_as(&Fraction::ZERO));
+    }
+
+    #[test]
+    #[should_panic(expected = "zero to a negative power")]
+    fn pow_of_zero_negative_exponent_panics() {
+        Fraction::ZERO.pow(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "power with overflow")]
+    fn pow_overflow_panics() {
+        Fraction::new(1, 10).pow(19);
+    }
+
+    #[test]
+    fn checked_pow() {
+        assert_eq!(Fraction::new(2, 3).checked_pow(3), Some(Fraction::new(8, 27)));
+        assert_eq!(Fraction::new(2, 3).checked_pow(-2), Some(Fraction::new(9, 4)));
+        assert_eq!(Fraction::ZERO.checked_pow(0), Some(Fraction::ONE));
+        assert_eq!(Fraction::new(i64::MIN, -1).checked_pow(0), Some(Fraction::ONE));
+    }
+
+    #[test]
+    fn checked_pow_near_limit() {
+        assert_eq!(Fraction::from(2).checked_pow(62), Some(Fraction::new(1 << 62, 1)));
+        assert_eq!(Fraction::from(2).checked_pow(63), None);
+        assert_eq!(Fraction::new(1, 2).checked_pow(-62), Some(Fraction::new(1 << 62, 1)));
+        assert_eq!(Fraction::new(1, 3).checked_pow(39), Some(Fraction::new(1, 3i64.pow(39))));
+        assert_eq!(Fraction::new(1, 3).checked_pow(40), None);
+    }
+
+    #[test]
+    fn checked_pow_overflow() {
+        assert_eq!(Fraction::from(100).checked_pow(9), Some(Fraction::from(1_000_000_000_000_000_000i64)));
+        assert_eq!(Fraction::from(100).checked_pow(10), None);
+        assert_eq!(Fraction::from(100).checked_pow(i32::MAX), None);
+        assert_eq!(Fraction::from(100).checked_pow(i32::MIN), None);
+        assert_eq!(Fraction::new(-1, i64::MIN).checked_pow(1), None);
+        assert_eq!(Fraction::new(-1, i64::MIN).checked_pow(-1), None);
+        assert_eq!(Fraction::new(i64::MIN, -1).checked_pow(1), None);
+    }
+
+    #[test]
+    fn checked_pow_of_zero_negative_exponent() {
+        assert_eq!(Fraction::ZERO.checked_pow(-1), None);
+    }
+
+    #[test]
+    fn checked_pow_of_one() {
+        assert_eq!(Fraction::ONE.checked_pow(i32::MAX), Some(Fraction::ONE));
+        assert_eq!(Fraction::from(-1).checked_pow(i32::MIN), Some(Fraction::ONE));
+    }
+
+    #[test]
+    fn remainder_signs() {
+        assert_eq!(Fraction::new(7, 2) % Fraction::new(4, 3), Fraction::new(5, 6));
+        assert_eq!(Fraction::new(-7, 2) % Fraction::new(4, 3), Fraction::new(-5, 6));
+        assert_eq!(Fraction::new(7, 2) % Fraction::new(-4, 3), Fraction::new(5, 6));
+        assert_eq!(Fraction::new(-7, 2) % Fraction::new(-4, 3), Fraction::new(-5, 6));
+    }
+
+    #[test]
+    fn remainder_matches_truncated_division() {
+        let values = [
+            Fraction::new(7, 2),
+            Fraction::new(-7, 2),
+            Fraction::new(4, -3),
+            Fraction::new(-5, -6),
+            Fraction::new(9, 1),
+            Fraction::new(0, 4),
+        ];
+        for &a in values.iter() {
+            for &b in values.iter().filter(|b| !b.is_zero()) {
+                let truncated = Fraction::from((a / b).trunc());
+                assert_eq!(a, truncated * b + a % b, "{} % {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn remainder_of_exact_multiple() {
+        let result = Fraction::new(9, 4) % Fraction::new(3, 4);
+        assert!(result.is_zero());
+    }
+
+    #[test]
+    fn remainder_with_integers() {
+        assert_eq!(Fraction::new(23, 4) % 2, Fraction::new(7, 4));
+        assert_eq!(7 % Fraction::new(3, 2), Fraction::ONE);
+        assert_eq!(Fraction::new(-23, 4) % 2i64, Fraction::new(-7, 4));
+    }
+
+    #[test]
+    fn remainder_assign() {
+        let mut f1 = Fraction::new(23, 4);
+        f1 %= Fraction::new(1, 2);
+        assert_eq!(f1, Fraction::new(1, 4));
+        f1 %= 1;
+        assert_eq!(f1, Fraction::new(1, 4));
+    }
+
+    #[test]
+    fn checked_rem_by_zero() {
+        assert_eq!(Fraction::new(1, 2).checked_rem(Fraction::new(0, 3)), None);
+        assert_eq!(Fraction::new(1, 2).checked_rem_euclid(Fraction::ZERO), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "remainder of a fraction with a divisor of zero")]
+    fn remainder_by_zero_panics() {
+        let _ = Fraction::new(1, 2) % Fraction::new(0, 3);
+    }
+
+    #[test]
+    fn rem_euclid_signs() {
+        assert_eq!(Fraction::new(7, 2).rem_euclid(Fraction::new(4, 3)), Fraction::new(5, 6));
+        assert_eq!(Fraction::new(-7, 2).rem_euclid(Fraction::new(4, 3)), Fraction::new(1, 2));
+        assert_eq!(Fraction::new(7, 2).rem_euclid(Fraction::new(-4, 3)), Fraction::new(5, 6));
+        assert_eq!(Fraction::new(-7, 2).rem_euclid(Fraction::new(-4, 3)), Fraction::new(1, 2));
+    }
+
+    #[test]
+    fn rem_euclid_of_angle() {
+        let turn = Fraction::from(360);
+        assert_eq!(Fraction::new(-1081, 2).rem_euclid(turn), Fraction::new(359, 2));
+    }
+
+    #[test]
+    fn min_and_max() {
+        assert!(Fraction::new(1, 3).min(Fraction::new(1, 2)).is_same_as(&Fraction::new(1, 3)));
+        assert!(Fraction::new(1, 3).max(Fraction::new(1, 2)).is_same_as(&Fraction::new(1, 2)));
+        assert!(Fraction::new(-1, 2).min(Fraction::new(1, -3)).is_same_as(&Fraction::new(-1, 2)));
+    }
+
+    #[test]
+    fn min_and_max_of_equal_values() {
+        let f1 = Fraction::new(1, 2);
+        let f2 = Fraction::new(-2, -4);
+        assert!(f1.min(f2).is_same_as(&f1));
+        assert!(f1.max(f2).is_same_as(&f2));
+        assert!(f1.min(f2).is_same_as(&std::cmp::min(f1, f2)));
+        assert!(f1.max(f2).is_same_as(&std::cmp::max(f1, f2)));
+    }
+
+    #[test]
+    fn min_and_max_without_overflow() {
+        let f1 = Fraction::new(i64::MAX, i64::MAX - 1);
+        let f2 = Fraction::new(i64::MAX - 1, i64::MAX - 2);
+        assert!(f1.min(f2).is_same_as(&f1));
+        assert!(f1.max(f2).is_same_as(&f2));
+    }
+
+    #[test]
+    fn clamp_with_negative_bounds() {
+        let lo = Fraction::new(-3, 2);
+        let hi = Fraction::new(1, 4);
+        assert!(Fraction::from(-2).clamp(lo, hi).is_same_as(&lo));
+        assert!(Fraction::new(1, 3).clamp(lo, hi).is_same_as(&hi));
+        assert!(Fraction::new(-1, 2).clamp(lo, hi).is_same_as(&Fraction::new(-1, 2)));
+    }
+
+    #[test]
+    fn clamp_to_equal_bound() {
+        let f1 = Fraction::new(6, -4);
+        let result = f1.clamp(Fraction::new(-3, 2), Fraction::ONE);
+        assert!(result.is_same_as(&f1));
+    }
+
+    #[test]
+    #[should_panic(expected = "lower bound above the upper bound")]
+    fn clamp_with_reversed_bounds() {
+        let _ = Fraction::ZERO.clamp(Fraction::ONE, Fraction::new(1, 2));
+    }
+
+    #[test]
+    fn mediant() {
+        assert!(Fraction::new(1, 2).mediant(&Fraction::new(2, 3)).is_same_as(&Fraction::new(3, 5)));
+        assert!(Fraction::ZERO.mediant(&Fraction::ONE).is_same_as(&Fraction::new(1, 2)));
+    }
+
+    #[test]
+    fn mediant_reduces_inputs() {
+        let result = Fraction::new(2, 4).mediant(&Fraction::new(1, 3));
+        assert!(result.is_same_as(&Fraction::new(2, 5)));
+    }
+
+    #[test]
+    fn mediant_with_negative_denominators() {
+        let result = Fraction::new(1, -2).mediant(&Fraction::new(-1, -3));
+        assert!(result.is_same_as(&Fraction::ZERO));
+        let result = Fraction::new(3, -4).mediant(&Fraction::new(-1, 2));
+        assert!(result.is_same_as(&Fraction::new(-2, 3)));
+    }
+
+    #[test]
+    fn checked_mediant_overflow() {
+        let f1 = Fraction::new(1, i64::MAX);
+        assert_eq!(f1.checked_mediant(&Fraction::new(1, 2)), None);
+        assert_eq!(f1.checked_mediant(&Fraction::new(1, -2)), Some(Fraction::new(0, 1)));
+    }
+
+    #[test]
+    fn between_random_pairs() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(400);
+        for _ in 0..10_000 {
+            let f1 = Fraction::new(rng.gen_range(-1000..=1000), rng.gen_range(1..=1000));
+            let f2 = Fraction::new(rng.gen_range(-1000..=1000), rng.gen_range(1..=1000));
+            if f1 == f2 {
+                continue;
+            }
+            let (lo, hi) = if f1 < f2 { (f1, f2) } else { (f2, f1) };
+            let result = f1.between(&f2);
+            assert!(lo < result && result < hi, "{} between {} and {}", result, f1, f2);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "between two equal fractions")]
+    fn between_equal_fractions() {
+        let _ = Fraction::new(1, 2).between(&Fraction::new(2, 4));
+    }
+
+    #[test]
+    fn limit_denominator_of_decimal_expansion() {
+        let result = Fraction::new(314_159_292_035, 100_000_000_000).limit_denominator(1000);
+        assert!(result.is_same_as(&Fraction::new(355, 113)));
+    }
+
+    #[test]
+    fn limit_denominator_of_pi() {
+        let pi = Fraction::new(314_159_265_358_979, 100_000_000_000_000);
+        assert!(pi.limit_denominator(10).is_same_as(&Fraction::new(22, 7)));
+        assert!(pi.limit_denominator(100).is_same_as(&Fraction::new(311, 99)));
+        assert!(pi.limit_denominator(1).is_same_as(&Fraction::from(3)));
+        assert!((-pi).limit_denominator(10).is_same_as(&Fraction::new(-22, 7)));
+    }
+
+    #[test]
+    fn limit_denominator_of_repeating_decimal() {
+        let result = Fraction::new(333_333_333, 1_000_000_000).limit_denominator(100);
+        assert!(result.is_same_as(&Fraction::new(1, 3)));
+    }
+
+    #[test]
+    fn limit_denominator_under_bound() {
+        assert!(Fraction::new(3, 7).limit_denominator(7).is_same_as(&Fraction::new(3, 7)));
+        assert!(Fraction::new(6, -14).limit_denominator(10).is_same_as(&Fraction::new(-3, 7)));
+        assert!(Fraction::from(i64::MIN).limit_denominator(1).is_same_as(&Fraction::from(i64::MIN)));
+    }
+
+    #[test]
+    fn limit_denominator_tie_prefers_smaller_denominator() {
+        // 3/4 is exactly halfway between 1/2 and 1, and 5/12 between 1/3 and 1/2
+        assert!(Fraction::new(3, 4).limit_denominator(2).is_same_as(&Fraction::ONE));
+        assert!(Fraction::new(-3, 4).limit_denominator(2).is_same_as(&Fraction::from(-1)));
+        assert!(Fraction::new(1, 4).limit_denominator(2).is_same_as(&Fraction::ZERO));
+        assert!(Fraction::new(5, 12).limit_denominator(3).is_same_as(&Fraction::new(1, 2)));
+    }
+
+    #[test]
+    fn limit_denominator_matches_brute_force() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(401);
+        for _ in 0..1000 {
+            let f1 = Fraction::new(rng.gen_range(-10_000..=10_000), rng.gen_range(1..=10_000));
+            let bound = rng.gen_range(1..=50);
+            let result = f1.limit_denominator(bound);
+            let best = (1..=bound)
+                .map(|d| Fraction::new((f1 * d).round(), d))
+                .min_by_key(|&candidate| ((candidate - f1).abs(), candidate.clone_simplified().denominator()))
+                .unwrap();
+            assert!(result.is_same_as(&best.clone_simplified()), "{} limited to {}", f1, bound);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "less than one")]
+    fn limit_denominator_to_zero() {
+        let _ = Fraction::new(1, 3).limit_denominator(0);
+    }
+
+    #[test]
+    fn to_f64() {
+        assert_eq!(Fraction::new(1, 3).to_f64(), 1.0 / 3.0);
+        assert_eq!(Fraction::new(-3, 4).to_f64(), -0.75);
+        assert_eq!(Fraction::new(3, -4).to_f64(), -0.75);
+        assert_eq!(Fraction::new(0, -5).to_f64(), 0.0);
+        assert_eq!(f64::from(Fraction::new(7, 2)), 3.5);
+    }
+
+    #[test]
+    fn to_f64_beyond_mantissa() {
+        assert_eq!(Fraction::from(i64::MAX).to_f64(), 9_223_372_036_854_775_807.0);
+        assert_eq!(Fraction::from(i64::MIN).to_f64(), -9_223_372_036_854_775_808.0);
+        assert_eq!(Fraction::new(1, i64::MIN).to_f64(), -1.0 / 9_223_372_036_854_775_808.0);
+        // Halfway between two doubles rounds to even
+        assert_eq!(Fraction::from((1i64 << 53) + 1).to_f64(), (1u64 << 53) as f64);
+        // Dividing the nearest doubles to 2^54 + 1 and 2^54 - 1 would give exactly 1.0
+        let result = Fraction::new((1 << 54) + 1, (1 << 54) - 1).to_f64();
+        assert_eq!(result, 1.0 + f64::EPSILON);
+    }
+
+    #[test]
+    fn to_f64_matches_float_division() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        // Both halves are exact as doubles here, so the division is the only rounding step
+        let mut rng = StdRng::seed_from_u64(402);
+        for _ in 0..10_000 {
+            let numerator: i64 = rng.gen_range(-(1 << 53)..=1 << 53);
+            let denominator: i64 = rng.gen_range(1..=1 << 53);
+            let expected = numerator as f64 / denominator as f64;
+            assert_eq!(Fraction::new(numerator, denominator).to_f64(), expected);
+        }
+    }
+
+    #[test]
+    fn to_f64_is_never_nan() {
+        assert!(Fraction::try_new(1, 0).is_err());
+        let extremes = [i64::MIN, -1, 1, i64::MAX];
+        for &n in extremes.iter() {
+            for &d in extremes.iter() {
+                assert!(Fraction::new(n, d).to_f64().is_finite());
+                assert!(Fraction::new(n, d).to_f32().is_finite());
+            }
+        }
+    }
+
+    #[test]
+    fn to_f32() {
+        assert_eq!(Fraction::new(1, 3).to_f32(), 1.0f32 / 3.0);
+        assert_eq!(Fraction::new(-5, 8).to_f32(), -0.625);
+        assert_eq!(f32::from(Fraction::from(i64::MAX)), 9_223_372_036_854_775_807.0f32);
+    }
+
+    #[test]
+    fn compare_with_f64() {
+        assert!(Fraction::new(1, 3) != 1.0 / 3.0);
+        assert!(Fraction::new(1, 3) > 1.0 / 3.0);
+        assert!(Fraction::new(1, 10) < 0.1);
+        assert!(Fraction::new(3, 4) == 0.75);
+        assert!(Fraction::new(1, 3) < 0.34);
+        assert!(Fraction::new(-1, 3) > -0.34);
+        assert!(Fraction::new(1, 3).partial_cmp(&f64::NAN).is_none());
+    }
+
+    #[test]
+    fn compare_with_f64_beyond_rounding() {
+        // Both round to 2^53 as an f64, so comparing after converting would call them equal
+        let above = Fraction::from_int((1 << 53) + 1);
+        assert!(above > 9_007_199_254_740_992.0);
+        assert!(above != 9_007_199_254_740_992.0);
+        assert_eq!(above.to_f64(), 9_007_199_254_740_992.0);
+        assert!(Fraction::new(-(1 << 53) - 1, 1) < -9_007_199_254_740_992.0);
+    }
+
+    #[test]
+    fn compare_with_f64_that_from_f64_exact_rejects() {
+        // 2^63 is just past i64::MAX, and 1e-4 and 1e-5 have power of two denominators beyond 2^64
+        assert!(Fraction::from(i64::MAX) != 9_223_372_036_854_775_808.0);
+        assert!(Fraction::from(i64::MAX) < 9_223_372_036_854_775_808.0);
+        assert!(Fraction::new(i64::MIN, -1) == 9_223_372_036_854_775_808.0);
+        assert!(Fraction::from(i64::MIN) == -9_223_372_036_854_775_808.0);
+        assert!(Fraction::new(1, 10000) != 1e-4);
+        assert!(Fraction::new(1, 10000) < 1e-4);
+        assert!(Fraction::new(-1, 10000) > -1e-4);
+        assert!(Fraction::new(1, 100000) != 1e-5);
+        assert!(Fraction::new(1, 1 << 62) == 2f64.powi(-62));
+        assert!(Fraction::new(1, 1 << 62) > 2f64.powi(-64));
+        assert!(Fraction::ZERO == -0.0);
+    }
 
-// Another option would be the Binary GCD algorithm (https://en.wikipedia.org/wiki/Binary_GCD_algorithm)
-fn gcd(mut a: i32, mut b: i32) -> i32 {
-    // Convert a and b to positive integers
-    if a < 0 {
-        a *= -1;
+    #[test]
+    fn compare_with_f64_outside_fraction_range() {
+        assert!(Fraction::from(i64::MAX) < 1e300);
+        assert!(Fraction::from(i64::MIN) > -1e300);
+        assert!(Fraction::new(1, i64::MAX) > 1e-300);
+        assert!(Fraction::ZERO < 1e-300);
+        assert!(Fraction::ZERO > -f64::from_bits(1));
+        assert!(Fraction::new(-1, i64::MAX) < -1e-300);
+        assert!(Fraction::from(i64::MAX) < f64::INFINITY);
+        assert!(Fraction::from(i64::MIN) > f64::NEG_INFINITY);
     }
-    if b < 0 {
-        b *= -1;
+
+    #[test]
+    fn compare_with_integers() {
+        let f = Fraction::new(7, 2);
+        assert!(f > 3 && f < 4);
+        assert!(3 < f && 4 > f);
+        assert!(f > 3i64 && f < 4i64);
+        assert!(3i64 < f && 4i64 > f);
+        assert!(Fraction::new(-7, 2) < -3 && Fraction::new(-7, 2) > -4);
+        assert!(Fraction::new(7, -2) < -3i64 && -4i64 < Fraction::new(7, -2));
+        assert!(Fraction::new(8, 2) >= 4 && Fraction::new(8, 2) <= 4i64);
     }
 
-    // Calculate the GCD
-    while a != b {
-        match a.cmp(&b) {
-            Ordering::Less => b -= a,
-            Ordering::Equal => break,
-            Ordering::Greater => a -= b,
-        }
+    #[test]
+    fn compare_with_i64_extremes() {
+        assert!(Fraction::new(i64::MAX, 2) < i64::MAX);
+        assert!(Fraction::new(i64::MIN, -1) > i64::MAX);
+        assert!(i64::MIN < Fraction::new(i64::MIN, 2));
+        assert_eq!(Fraction::new(i64::MIN, 1).partial_cmp(&i64::MIN), Some(std::cmp::Ordering::Equal));
+        assert!(Fraction::new(i64::MAX, i64::MAX) >= 1i64);
     }
-    a
-}
 
-#[derive(Debug, Clone, Copy)]
-pub struct Fraction {
-    numerator: i32,
-    denominator: i32,
-}
+    #[test]
+    fn from_f64_exact() {
+        assert!(Fraction::from_f64_exact(0.5).unwrap().is_same_as(&Fraction::new(1, 2)));
+        assert!(Fraction::from_f64_exact(-2.5).unwrap().is_same_as(&Fraction::new(-5, 2)));
+        assert!(Fraction::from_f64_exact(3.0).unwrap().is_same_as(&Fraction::from(3)));
+        assert!(Fraction::from_f64_exact(-0.0).unwrap().is_same_as(&Fraction::ZERO));
+    }
 
-impl Fraction {
-    pub fn new(numerator: i32, denominator: i32) -> Self {
-        Self {
-            numerator,
-            denominator,
-        }
+    #[test]
+    fn from_f64_exact_one_tenth() {
+        let result = Fraction::from_f64_exact(0.1).unwrap();
+        assert!(result.is_same_as(&Fraction::new(3_602_879_701_896_397, 36_028_797_018_963_968)));
+        assert!(result.limit_denominator(100).is_same_as(&Fraction::new(1, 10)));
     }
 
-    pub fn clone_simplified(&self) -> Self {
-        let mut f = self.clone();
-        f.simplify();
-        f
+    #[test]
+    fn from_f64_exact_limits() {
+        let result = Fraction::from_f64_exact(-9_223_372_036_854_775_808.0).unwrap();
+        assert!(result.is_same_as(&Fraction::from(i64::MIN)));
+        let result = Fraction::from_f64_exact(1.0 / 4_611_686_018_427_387_904.0).unwrap();
+        assert!(result.is_same_as(&Fraction::new(1, 1 << 62)));
+        assert_eq!(Fraction::from_f64_exact(9_223_372_036_854_775_808.0), Err(FromFloatError::Overflow));
+        assert_eq!(Fraction::from_f64_exact(1.0 / 9_223_372_036_854_775_808.0), Err(FromFloatError::Overflow));
+        assert_eq!(Fraction::from_f64_exact(1e300), Err(FromFloatError::Overflow));
+        assert_eq!(Fraction::from_f64_exact(f64::MIN_POSITIVE / 2.0), Err(FromFloatError::Overflow));
     }
 
-    pub fn simplify(&mut self) {
-        if self.is_zero() || self.is_undefined() {
-            return;
-        }
+    #[test]
+    fn from_f64_exact_not_finite() {
+        assert_eq!(Fraction::from_f64_exact(f64::NAN), Err(FromFloatError::NotFinite));
+        assert_eq!(Fraction::from_f64_exact(f64::INFINITY), Err(FromFloatError::NotFinite));
+        assert_eq!(Fraction::from_f64_exact(f64::NEG_INFINITY), Err(FromFloatError::NotFinite));
+    }
 
-        let gcd = gcd(self.numerator, self.denominator);
-        self.numerator /= gcd;
-        self.denominator /= gcd;
+    #[test]
+    fn from_f64_exact_round_trip() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
 
-        if self.denominator < 0 {
-            self.numerator *= -1;
-            self.denominator *= -1;
+        let mut rng = StdRng::seed_from_u64(403);
+        for _ in 0..10_000 {
+            let x: f64 = rng.gen_range(-1e6..1e6);
+            assert_eq!(Fraction::from_f64_exact(x).unwrap().to_f64(), x);
         }
     }
 
-    pub fn reciprocal(&self) -> Self {
-        Fraction::new(self.denominator, self.numerator)
+    #[test]
+    fn approx_from_f64() {
+        let result = Fraction::approx_from_f64(0.3333333, 1e-6).unwrap();
+        assert!(result.is_same_as(&Fraction::new(1, 3)));
+        let result = Fraction::approx_from_f64(0.1, 1e-12).unwrap();
+        assert!(result.is_same_as(&Fraction::new(1, 10)));
+        let result = Fraction::approx_from_f64(-0.75, 1e-12).unwrap();
+        assert!(result.is_same_as(&Fraction::new(-3, 4)));
     }
 
-    pub fn is_same_as(&self, other: &Self) -> bool {
-        self.numerator == other.numerator && self.denominator == other.denominator
+    #[test]
+    fn approx_from_f64_loose_tolerance() {
+        let result = Fraction::approx_from_f64(3.142857, 1e-3).unwrap();
+        assert!(result.is_same_as(&Fraction::new(22, 7)));
+        let result = Fraction::approx_from_f64(std::f64::consts::PI, 1e-6).unwrap();
+        assert!(result.is_same_as(&Fraction::new(355, 113)));
+        let result = Fraction::approx_from_f64(0.01, 0.1).unwrap();
+        assert!(result.is_same_as(&Fraction::ZERO));
     }
 
-    pub fn is_zero(&self) -> bool {
-        self.numerator == 0
+    #[test]
+    fn approx_from_f64_whole_numbers() {
+        assert!(Fraction::approx_from_f64(5.0, 1e-9).unwrap().is_same_as(&Fraction::from(5)));
+        assert!(Fraction::approx_from_f64(-42.0, 1e-9).unwrap().is_same_as(&Fraction::from(-42)));
+        let result = Fraction::approx_from_f64(1e15, 1e-9).unwrap();
+        assert!(result.is_same_as(&Fraction::from(1_000_000_000_000_000i64)));
+        // Past 2^53 neighbouring integers round to the same float, and any of them is as close
+        assert!(Fraction::approx_from_f64(1e18, 1e-9).unwrap().is_integer());
     }
 
-    pub fn is_undefined(&self) -> bool {
-        self.denominator == 0
+    #[test]
+    fn approx_from_f64_errors() {
+        assert_eq!(Fraction::approx_from_f64(f64::NAN, 0.1), Err(FromFloatError::NotFinite));
+        assert_eq!(Fraction::approx_from_f64(f64::INFINITY, 0.1), Err(FromFloatError::NotFinite));
+        assert_eq!(Fraction::approx_from_f64(0.5, 0.0), Err(FromFloatError::InvalidTolerance));
+        assert_eq!(Fraction::approx_from_f64(0.5, -1.0), Err(FromFloatError::InvalidTolerance));
+        assert_eq!(Fraction::approx_from_f64(0.5, f64::NAN), Err(FromFloatError::InvalidTolerance));
+        assert_eq!(Fraction::approx_from_f64(1e30, 0.5), Err(FromFloatError::Overflow));
+        assert_eq!(Fraction::approx_from_f64(1e-30, 1e-40), Err(FromFloatError::Overflow));
     }
-}
 
-impl Display for Fraction {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        if self.denominator == 1 {
-            write!(f, "{}", self.numerator)
-        } else {
-            write!(f, "{}/{}", self.numerator, self.denominator)
+    #[test]
+    fn approx_from_f64_within_tolerance() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(404);
+        for _ in 0..10_000 {
+            let x: f64 = rng.gen_range(-1000.0..1000.0);
+            let max_error = 10f64.powi(rng.gen_range(-12..0));
+            let result = Fraction::approx_from_f64(x, max_error).unwrap();
+            assert!((result.to_f64() - x).abs() <= max_error, "{} within {} of {}", result, max_error, x);
         }
     }
-}
-
-impl FromStr for Fraction {
-    type Err = ParseFractionError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(i) = s.find('/') {
-            let numerator = match s[..i].parse() {
-                Ok(n) => n,
-                Err(_) => return Err(ParseFractionError),
-            };
-            let denominator = match s[i+1..].parse() {
-                Ok(d) => d,
-                Err(_) => return Err(ParseFractionError),
-            };
+    #[test]
+    fn approx_from_f64_smallest_denominator() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
 
-            return Ok(Fraction::new(numerator, denominator))
+        let mut rng = StdRng::seed_from_u64(404);
+        for _ in 0..1000 {
+            let x: f64 = rng.gen_range(-10.0..10.0);
+            let max_error = 1e-4;
+            let result = Fraction::approx_from_f64(x, max_error).unwrap();
+            for d in 1..result.denominator() {
+                let closest = Fraction::new((x * d as f64).round() as i64, d);
+                assert!((closest.to_f64() - x).abs() > max_error, "{} is closer to {} than {}", closest, x, result);
+            }
         }
+    }
 
-        if let Ok(num) = s.parse::<i32>() {
-            return Ok(Fraction::from(num))
-        }
+    #[test]
+    fn continued_fraction() {
+        assert_eq!(Fraction::new(355, 113).continued_fraction(), vec![3, 7, 16]);
+        assert_eq!(Fraction::new(415, 93).continued_fraction(), vec![4, 2, 6, 7]);
+        assert_eq!(Fraction::new(1, 2).continued_fraction(), vec![0, 2]);
+    }
 
-        Err(ParseFractionError)
+    #[test]
+    fn continued_fraction_of_negatives() {
+        assert_eq!(Fraction::new(-415, 93).continued_fraction(), vec![-5, 1, 1, 6, 7]);
+        assert_eq!(Fraction::new(6, -4).continued_fraction(), vec![-2, 2]);
     }
-}
 
-impl From<i32> for Fraction {
-    fn from(num: i32) -> Self {
-        Fraction::new(num, 1)
+    #[test]
+    fn continued_fraction_of_integers() {
+        assert_eq!(Fraction::from(7).continued_fraction(), vec![7]);
+        assert_eq!(Fraction::new(-6, 2).continued_fraction(), vec![-3]);
+        assert_eq!(Fraction::ZERO.continued_fraction(), vec![0]);
+        assert_eq!(Fraction::from(i64::MIN).continued_fraction(), vec![i64::MIN]);
     }
-}
 
-impl Add for Fraction {
-    type Output = Self;
+    #[test]
+    fn from_continued_fraction() {
+        assert!(Fraction::from_continued_fraction(&[3, 7, 16]).is_same_as(&Fraction::new(355, 113)));
+        assert!(Fraction::from_continued_fraction(&[-5, 1, 1, 6, 7]).is_same_as(&Fraction::new(-415, 93)));
+        // A final term of one is the same as adding one to the term before it
+        assert!(Fraction::from_continued_fraction(&[0, 1, 1]).is_same_as(&Fraction::new(1, 2)));
+    }
 
-    fn add(self, other: Self) -> Self::Output {
-        if self.denominator == other.denominator {
-            Fraction::new(self.numerator + other.numerator, self.denominator)
-        } else {
-            Fraction::new(
-                self.numerator * other.denominator + other.numerator * self.denominator,
-                self.denominator * other.denominator,
-            )
+    #[test]
+    #[should_panic(expected = "empty continued fraction")]
+    fn from_empty_continued_fraction() {
+        let _ = Fraction::from_continued_fraction(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero denominator")]
+    fn from_continued_fraction_with_zero_denominator() {
+        let _ = Fraction::from_continued_fraction(&[1, 0]);
+    }
+
+    #[test]
+    fn continued_fraction_round_trip() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(405);
+        for _ in 0..10_000 {
+            let f1 = Fraction::new(rng.gen_range(i64::MIN + 1..=i64::MAX), rng.gen_range(1..=i64::MAX));
+            let terms = f1.continued_fraction();
+            assert!(terms.len() == 1 || *terms.last().unwrap() > 1, "{:?}", terms);
+            assert!(Fraction::from_continued_fraction(&terms).is_same_as(&f1.clone_simplified()), "{}", f1);
         }
     }
-}
 
-impl Sub for Fraction {
-    type Output = Self;
+    #[test]
+    fn from_string_mixed_number() {
+        assert!(Fraction::from_str("1 3/4").unwrap().is_same_as(&Fraction::new(7, 4)));
+        assert!(Fraction::from_str("1_3/4").unwrap().is_same_as(&Fraction::new(7, 4)));
+        assert!(Fraction::from_str("+1 3/4").unwrap().is_same_as(&Fraction::new(7, 4)));
+        assert!(Fraction::from_str("2 2/4").unwrap().is_same_as(&Fraction::new(5, 2)));
+    }
 
-    fn sub(self, other: Self) -> Self::Output {
-        self + -other
+    #[test]
+    fn from_string_negative_mixed_number() {
+        assert!(Fraction::from_str("-2 1/2").unwrap().is_same_as(&Fraction::new(-5, 2)));
+        assert!(Fraction::from_str("-2_1/2").unwrap().is_same_as(&Fraction::new(-5, 2)));
     }
-}
 
-impl Neg for Fraction {
-    type Output = Self;
+    #[test]
+    fn from_string_mixed_number_zero_whole() {
+        assert!(Fraction::from_str("0 1/2").unwrap().is_same_as(&Fraction::new(1, 2)));
+        assert!(Fraction::from_str("-0 1/2").unwrap().is_same_as(&Fraction::new(-1, 2)));
+    }
 
-    fn neg(self) -> Self::Output {
-        Fraction::new(-self.numerator, self.denominator)
+    #[test]
+    fn from_string_invalid_mixed_number() {
+        assert_eq!(Fraction::from_str("1 -3/4").unwrap_err(), ParseFractionError::InvalidMixedNumber { text: "-3/4".to_string() });
+        assert_eq!(Fraction::from_str("1 3").unwrap_err(), ParseFractionError::InvalidMixedNumber { text: "3".to_string() });
+        assert_eq!(Fraction::from_str("1_3").unwrap_err(), ParseFractionError::InvalidMixedNumber { text: "3".to_string() });
+        assert_eq!(Fraction::from_str("1 3/0").unwrap_err(), ParseFractionError::ZeroDenominator);
+        assert_eq!(Fraction::from_str("1 5/4").unwrap_err(), ParseFractionError::InvalidMixedNumber { text: "5/4".to_string() });
+        assert_eq!(Fraction::from_str("1_4/4").unwrap_err(), ParseFractionError::InvalidMixedNumber { text: "4/4".to_string() });
     }
-}
 
-impl Mul for Fraction {
-    type Output = Self;
+    #[test]
+    fn from_string_mixed_number_overflow() {
+        let result = Fraction::from_str("9223372036854775807 1/2");
+        assert_eq!(result.unwrap_err(), ParseFractionError::Overflow);
+        let result = Fraction::from_str("-4611686018427387903 1/2");
+        assert!(result.unwrap().is_same_as(&Fraction::new(-i64::MAX, 2)));
+        let result = Fraction::from_str("-4611686018427387904 1/2");
+        assert_eq!(result.unwrap_err(), ParseFractionError::Overflow);
+    }
 
-    fn mul(self, other: Self) -> Self::Output {
-        Fraction::new(
-            self.numerator * other.numerator,
-            self.denominator * other.denominator,
-        )
+    #[test]
+    fn from_string_decimal() {
+        assert!(Fraction::from_str("0.75").unwrap().is_same_as(&Fraction::new(3, 4)));
+        assert!(Fraction::from_str("0.5").unwrap().is_same_as(&Fraction::new(1, 2)));
+        assert!(Fraction::from_str("-2.25").unwrap().is_same_as(&Fraction::new(-9, 4)));
+        assert!(Fraction::from_str("-0.5").unwrap().is_same_as(&Fraction::new(-1, 2)));
+        assert!(Fraction::from_str("3.000").unwrap().is_same_as(&Fraction::from(3)));
     }
-}
 
-impl Div for Fraction {
-    type Output = Self;
+    #[test]
+    fn from_string_repeating_decimal() {
+        assert!(Fraction::from_str("0.(3)").unwrap().is_same_as(&Fraction::new(1, 3)));
+        assert!(Fraction::from_str("0.(142857)").unwrap().is_same_as(&Fraction::new(1, 7)));
+        assert!(Fraction::from_str("1.2(3)").unwrap().is_same_as(&Fraction::new(37, 30)));
+        assert!(Fraction::from_str("-0.1(6)").unwrap().is_same_as(&Fraction::new(-1, 6)));
+        assert!(Fraction::from_str("0.(9)").unwrap().is_same_as(&Fraction::ONE));
+    }
 
-    fn div(self, other: Self) -> Self::Output {
-        self * other.reciprocal()
+    #[test]
+    fn from_string_decimal_overflow() {
+        let result = Fraction::from_str("0.1234567890123456789");
+        assert_eq!(result.unwrap_err(), ParseFractionError::Overflow);
+        let result = Fraction::from_str("0.(1234567890123456789012345678901234567890)");
+        assert_eq!(result.unwrap_err(), ParseFractionError::Overflow);
+        let result = Fraction::from_str("0.0000000000000000000000000000000000000000001");
+        assert_eq!(result.unwrap_err(), ParseFractionError::Overflow);
     }
-}
 
-impl PartialEq for Fraction {
-    fn eq(&self, other: &Self) -> bool {
-        if self.is_same_as(&other) {
-            return true;
-        }
+    #[test]
+    fn from_string_invalid_decimal() {
+        assert_eq!(Fraction::from_str("0.333...").unwrap_err(), ParseFractionError::InvalidDecimal { text: "333...".to_string() });
+        assert_eq!(Fraction::from_str("1.2(3").unwrap_err(), ParseFractionError::InvalidDecimal { text: "2(3".to_string() });
+        assert_eq!(Fraction::from_str("1.5e3").unwrap_err(), ParseFractionError::InvalidDecimal { text: "5e3".to_string() });
+    }
 
-        let f1 = self.clone_simplified();
-        let f2 = other.clone_simplified();
-        f1.is_same_as(&f2)
+    #[test]
+    fn from_string_percent() {
+        assert!(Fraction::from_str("75%").unwrap().is_same_as(&Fraction::new(3, 4)));
+        assert!(Fraction::from_str("12.5%").unwrap().is_same_as(&Fraction::new(1, 8)));
+        assert!(Fraction::from_str("-30%").unwrap().is_same_as(&Fraction::new(-3, 10)));
+        assert!(Fraction::from_str("250%").unwrap().is_same_as(&Fraction::new(5, 2)));
+        assert!(Fraction::from_str("0.(3)%").unwrap().is_same_as(&Fraction::new(1, 300)));
     }
-}
 
-impl PartialEq<i32> for Fraction {
-    fn eq(&self, other: &i32) -> bool {
-        *self == Fraction::from(*other)
+    #[test]
+    fn from_string_misplaced_percent() {
+        assert_eq!(Fraction::from_str("%").unwrap_err(), ParseFractionError::EmptyInput);
+        assert_eq!(Fraction::from_str("%50").unwrap_err(), ParseFractionError::InvalidNumerator { text: "%50".to_string() });
+        assert_eq!(Fraction::from_str("5%0").unwrap_err(), ParseFractionError::TrailingGarbage { text: "%0".to_string() });
+        assert_eq!(Fraction::from_str("50%%").unwrap_err(), ParseFractionError::TrailingGarbage { text: "%%".to_string() });
+        assert_eq!(Fraction::from_str("50% ").unwrap_err(), ParseFractionError::TrailingGarbage { text: "% ".to_string() });
     }
-}
 
-impl PartialEq<Fraction> for i32 {
-    fn eq(&self, other: &Fraction) -> bool {
-        other == self
+    #[test]
+    fn from_string_errors() {
+        let invalid_numerator = |text: &str| ParseFractionError::InvalidNumerator { text: text.to_string() };
+        let invalid_denominator = |text: &str| ParseFractionError::InvalidDenominator { text: text.to_string() };
+        let trailing = |text: &str| ParseFractionError::TrailingGarbage { text: text.to_string() };
+
+        assert_eq!(Fraction::from_str("").unwrap_err(), ParseFractionError::EmptyInput);
+        assert_eq!(Fraction::from_str("3a/4").unwrap_err(), invalid_numerator("3a"));
+        assert_eq!(Fraction::from_str("x").unwrap_err(), invalid_numerator("x"));
+        assert_eq!(Fraction::from_str("3/").unwrap_err(), invalid_denominator(""));
+        assert_eq!(Fraction::from_str("3/four").unwrap_err(), invalid_denominator("four"));
+        assert_eq!(Fraction::from_str("3/4x").unwrap_err(), trailing("x"));
+        assert_eq!(Fraction::from_str("12 ").unwrap_err(), trailing(" "));
+        assert_eq!(Fraction::from_str("3/0").unwrap_err(), ParseFractionError::ZeroDenominator);
+        assert_eq!(Fraction::from_str("9223372036854775808").unwrap_err(), ParseFractionError::Overflow);
+        assert_eq!(Fraction::from_str("1/9223372036854775808").unwrap_err(), ParseFractionError::Overflow);
+        assert!(Fraction::from_str("-9223372036854775808").unwrap().is_same_as(&Fraction::from(i64::MIN)));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::fraction::Fraction;
-    use std::str::FromStr;
+    #[test]
+    fn parse_error_display() {
+        let error = Fraction::from_str("3a/4").unwrap_err();
+        assert_eq!(error.to_string(), "invalid numerator '3a'");
+        let error = Fraction::from_str("3/4x").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected 'x' after the number");
+    }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn is_undefined() {
-        assert!(Fraction::new(5, 0).is_undefined())
+    fn parse_error_is_std_error() {
+        let error: Box<dyn std::error::Error> = Box::new(ParseFractionError::ZeroDenominator);
+        assert_eq!(error.to_string(), "the denominator cannot be zero");
     }
 
     #[test]
-    fn is_zero() {
-        assert!(Fraction::new(0, 10).is_zero());
+    fn from_string_signs() {
+        assert!(Fraction::from_str("+3/4").unwrap().is_same_as(&Fraction::new(3, 4)));
+        assert!(Fraction::from_str("-3/4").unwrap().is_same_as(&Fraction::new(-3, 4)));
+        assert!(Fraction::from_str("+7").unwrap().is_same_as(&Fraction::from(7)));
+        let expected = ParseFractionError::InvalidDenominator { text: "-4".to_string() };
+        assert_eq!(Fraction::from_str("3/-4").unwrap_err(), expected);
     }
 
     #[test]
-    fn is_same_as() {
-        let f1 = Fraction::new(123, 456);
-        let f2 = Fraction::new(123, 456);
-        assert!(f1.is_same_as(&f2));
+    fn from_string_extra_slash() {
+        let expected = ParseFractionError::TrailingGarbage { text: "/3".to_string() };
+        assert_eq!(Fraction::from_str("1/2/3").unwrap_err(), expected);
     }
 
     #[test]
-    fn is_not_same_as() {
-        let f1 = Fraction::new(123, 456);
-        let f2 = Fraction::new(456, 123);
-        assert!(!f1.is_same_as(&f2));
+    fn from_string_internal_whitespace() {
+        let expected = ParseFractionError::InvalidMixedNumber { text: "/2".to_string() };
+        assert_eq!(Fraction::from_str("1 /2").unwrap_err(), expected);
+        let expected = ParseFractionError::InvalidDenominator { text: " 2".to_string() };
+        assert_eq!(Fraction::from_str("1/ 2").unwrap_err(), expected);
     }
 
     #[test]
-    fn reciprocal() {
-        let f1 = Fraction::new(123, 456);
-        let result = f1.clone().reciprocal();
-        let expected = Fraction::new(456, 123);
-        assert_eq!(result, expected);
+    fn display_sign_placements() {
+        assert_eq!(Fraction::new(1, 2).to_string(), "1/2");
+        assert_eq!(Fraction::new(-1, 2).to_string(), "-1/2");
+        assert_eq!(Fraction::new(1, -2).to_string(), "-1/2");
+        assert_eq!(Fraction::new(-1, -2).to_string(), "1/2");
+        assert_eq!(Fraction::new(-2, -4).to_string(), "2/4");
+        assert_eq!(Fraction::new(0, -5).to_string(), "0/5");
+        assert_eq!(Fraction::new(5, -1).to_string(), "-5");
+        assert_eq!(Fraction::new(i64::MIN, -1).to_string(), "9223372036854775808");
+        assert_eq!(Fraction::new(i64::MIN, i64::MIN).to_string(), "9223372036854775808/9223372036854775808");
     }
 
     #[test]
-    fn equality() {
-        let f1 = Fraction::new(6, 12);
-        let f2 = Fraction::new(1, 2);
-        assert_eq!(f1, f2);
+    fn other_renderers_sign_placements() {
+        for f in [Fraction::new(-3, 4), Fraction::new(3, -4)].iter() {
+            assert_eq!(format!("{:#}", f), "-3/4");
+            assert_eq!(f.to_latex(), r"-\frac{3}{4}");
+            assert_eq!(f.to_unicode_string(), "-¾");
+        }
+        for f in [Fraction::new(3, 4), Fraction::new(-3, -4)].iter() {
+            assert_eq!(format!("{:#}", f), "3/4");
+            assert_eq!(f.to_latex(), r"\frac{3}{4}");
+            assert_eq!(f.to_unicode_string(), "¾");
+        }
+        assert_eq!(format!("{:#}", Fraction::new(0, -5)), "0/5");
+        assert_eq!(Fraction::new(0, -5).to_latex(), "0");
+        assert_eq!(Fraction::new(0, -5).to_unicode_string(), "0");
     }
 
     #[test]
-    fn equality_with_int() {
-        let f1 = Fraction::new(3, 1);
-        let expected = f1.numerator;
-        assert_eq!(f1, expected);
+    fn display_mixed_improper() {
+        assert_eq!(format!("{:#}", Fraction::new(7, 4)), "1 3/4");
+        assert_eq!(format!("{:#}", Fraction::new(14, 8)), "1 6/8");
+        assert_eq!(format!("{}", Fraction::new(7, 4)), "7/4");
     }
 
     #[test]
-    fn equality_with_zero() {
-        assert_eq!(Fraction::new(0, 1), 0);
+    fn display_mixed_negative() {
+        assert_eq!(format!("{:#}", Fraction::new(-7, 4)), "-1 3/4");
+        assert_eq!(format!("{:#}", Fraction::new(7, -4)), "-1 3/4");
+        assert_eq!(format!("{:#}", Fraction::new(-7, -4)), "1 3/4");
     }
 
     #[test]
-    fn simplify_positive() {
-        let mut f1 = Fraction::new(8, 32);
-        f1.simplify();
-        let expected = Fraction::new(1, 4);
-        assert_eq!(f1.numerator, expected.numerator);
-        assert_eq!(f1.denominator, expected.denominator);
+    fn display_mixed_integers() {
+        assert_eq!(format!("{:#}", Fraction::from(5)), "5");
+        assert_eq!(format!("{:#}", Fraction::new(8, 4)), "2");
+        assert_eq!(format!("{:#}", Fraction::new(-8, 4)), "-2");
+        assert_eq!(format!("{:#}", Fraction::new(i64::MIN, -1)), "9223372036854775808");
     }
 
     #[test]
-    fn simplify_negative() {
-        let mut f1 = Fraction::new(-3, 9);
-        f1.simplify();
-        let expected = Fraction::new(-1, 3);
-        assert_eq!(f1.numerator, expected.numerator);
-        assert_eq!(f1.denominator, expected.denominator);
+    fn display_mixed_proper() {
+        assert_eq!(format!("{:#}", Fraction::new(3, 4)), "3/4");
+        assert_eq!(format!("{:#}", Fraction::new(-3, 4)), "-3/4");
+        assert_eq!(format!("{:#}", Fraction::ZERO), "0");
+        assert_eq!(format!("{:#}", Fraction::new(0, 5)), "0/5");
     }
 
     #[test]
-    fn simplify_zero() {
-        let mut f1 = Fraction::new(0, 1);
-        f1.simplify();
-        assert_eq!(f1, 0);
+    fn display_precision_repeating() {
+        assert_eq!(format!("{:.4}", Fraction::new(1, 3)), "0.3333");
+        assert_eq!(format!("{:.4}", Fraction::new(2, 3)), "0.6667");
+        assert_eq!(format!("{:.6}", Fraction::new(1, 7)), "0.142857");
+        assert_eq!(format!("{:.3}", Fraction::new(22, 7)), "3.143");
     }
 
     #[test]
-    fn simplify_double_negative() {
-        let mut f1 = Fraction::new(-5, -25);
-        f1.simplify();
-        let expected = Fraction::new(1, 5);
-        assert_eq!(f1.numerator, expected.numerator);
-        assert_eq!(f1.denominator, expected.denominator);
+    fn display_precision_terminating() {
+        assert_eq!(format!("{:.6}", Fraction::new(1, 4)), "0.250000");
+        assert_eq!(format!("{:.2}", Fraction::new(12, 4)), "3.00");
+        assert_eq!(format!("{:.0}", Fraction::new(7, 2)), "4");
+        assert_eq!(format!("{:.1}", Fraction::new(1, 20)), "0.1");
     }
 
     #[test]
-    fn addition_with_common_denominator() {
-        let f1 = Fraction::new(1, 5);
-        let f2 = Fraction::new(3, 5);
-        let result = f1 + f2;
-        let expected = Fraction::new(4, 5);
-        assert_eq!(result, expected);
+    fn display_precision_carry() {
+        assert_eq!(format!("{:.2}", Fraction::new(999, 1000)), "1.00");
+        assert_eq!(format!("{:.1}", Fraction::new(1999, 200)), "10.0");
     }
 
     #[test]
-    fn addition_without_common_denominator() {
-        let f1 = Fraction::new(1, 3);
-        let f2 = Fraction::new(1, 2);
-        let result = f1 + f2;
-        let expected = Fraction::new(5, 6);
-        assert_eq!(result, expected);
+    fn display_precision_negative() {
+        assert_eq!(format!("{:.4}", Fraction::new(-1, 3)), "-0.3333");
+        assert_eq!(format!("{:.2}", Fraction::new(5, -8)), "-0.63");
+        assert_eq!(format!("{:.2}", Fraction::new(-1, 1000)), "0.00");
+        assert_eq!(format!("{:.0}", Fraction::new(-7, 2)), "-4");
     }
 
     #[test]
-    fn addition_with_two_negatives() {
-        let f1 = Fraction::new(-2, 3);
-        let f2 = Fraction::new(-4, 8);
-        let result = f1 + f2;
-        let expected = Fraction::new(-7, 6);
-        assert_eq!(result, expected);
+    fn display_precision_large() {
+        let f1 = Fraction::new(i64::MAX, 3);
+        assert_eq!(format!("{:.5}", f1), "3074457345618258602.33333");
+        let f1 = Fraction::new(i64::MIN, i64::MAX);
+        assert_eq!(format!("{:.20}", f1), "-1.00000000000000000011");
     }
 
     #[test]
-    fn addition_with_one_negative_first() {
-        let f1 = Fraction::new(-7, 14);
-        let f2 = Fraction::new(1, 1);
-        let result = f1 + f2;
-        let expected = Fraction::new(1, 2);
-        assert_eq!(result, expected);
+    fn to_latex() {
+        assert_eq!(Fraction::new(3, 4).to_latex(), r"\frac{3}{4}");
+        assert_eq!(Fraction::new(7, 4).to_latex(), r"\frac{7}{4}");
+        assert_eq!(Fraction::new(-3, 4).to_latex(), r"-\frac{3}{4}");
+        assert_eq!(Fraction::new(3, -4).to_latex(), r"-\frac{3}{4}");
+        assert_eq!(Fraction::new(i64::MIN, 3).to_latex(), r"-\frac{9223372036854775808}{3}");
     }
 
     #[test]
-    fn addition_with_one_negative_last() {
-        let f1 = Fraction::new(3, 5);
-        let f2 = Fraction::new(-2, 5);
-        let result = f1 + f2;
-        let expected = Fraction::new(1, 5);
-        assert_eq!(result, expected);
+    fn to_latex_integers() {
+        assert_eq!(Fraction::from(5).to_latex(), "5");
+        assert_eq!(Fraction::new(5, -1).to_latex(), "-5");
+        assert_eq!(Fraction::ZERO.to_latex(), "0");
     }
 
     #[test]
-    fn addition_of_three_fractions() {
-        let f1 = Fraction::new(5, 4);
-        let f2 = Fraction::new(3, 4);
-        let f3 = Fraction::new(8, 4);
-        let result = f1 + f2 + f3;
-        let expected = 4;
-        assert_eq!(result, expected);
+    fn to_latex_mixed() {
+        assert_eq!(Fraction::new(7, 4).to_latex_mixed(), r"1\,\frac{3}{4}");
+        assert_eq!(Fraction::new(-7, 4).to_latex_mixed(), r"-1\,\frac{3}{4}");
+        assert_eq!(Fraction::new(3, 4).to_latex_mixed(), r"\frac{3}{4}");
+        assert_eq!(Fraction::new(8, -4).to_latex_mixed(), "-2");
+        assert_eq!(Fraction::from(5).to_latex_mixed(), "5");
     }
 
     #[test]
-    fn negative() {
-        let f1 = Fraction::new(4, 3);
-        let result = -f1;
-        let expected = Fraction::new(-4, 3);
-        assert_eq!(result, expected);
+    fn to_latex_reduces() {
+        assert_eq!(Fraction::new(2, -4).to_latex(), r"-\frac{1}{2}");
+        assert_eq!(Fraction::new(6, 3).to_latex(), "2");
+        assert_eq!(Fraction::new(-14, 8).to_latex_mixed(), r"-1\,\frac{3}{4}");
+        assert_eq!(Fraction::new(2, -4).to_latex_mixed(), r"-\frac{1}{2}");
+        assert_eq!(Fraction::new(i64::MIN, -2).to_latex_mixed(), "4611686018427387904");
     }
 
     #[test]
-    fn subtraction() {
-        let f1 = Fraction::new(6, 7);
-        let f2 = Fraction::new(2, 7);
-        let result = f1 - f2;
-        let expected = Fraction::new(4, 7);
-        assert_eq!(result, expected);
+    fn to_unicode_string_precomposed() {
+        assert_eq!(Fraction::new(1, 2).to_unicode_string(), "½");
+        assert_eq!(Fraction::new(2, 6).to_unicode_string(), "⅓");
+        assert_eq!(Fraction::new(3, 4).to_unicode_string(), "¾");
+        assert_eq!(Fraction::new(14, 16).to_unicode_string(), "⅞");
+        assert_eq!(Fraction::new(1, 10).to_unicode_string(), "⅒");
     }
 
     #[test]
-    fn from_string() {
-        let result = Fraction::from_str("6/18").unwrap();
-        let expected = Fraction::new(1, 3);
-        assert_eq!(result, expected);
+    fn to_unicode_string_composed() {
+        assert_eq!(Fraction::new(5, 17).to_unicode_string(), "⁵⁄₁₇");
+        assert_eq!(Fraction::new(246, 1357).to_unicode_string(), "²⁴⁶⁄₁₃₅₇");
+        assert_eq!(Fraction::new(11, 4).to_unicode_string(), "¹¹⁄₄");
+        assert_eq!(Fraction::new(10, 5).to_unicode_string(), "2");
+        assert_eq!(Fraction::ZERO.to_unicode_string(), "0");
     }
 
     #[test]
-    fn from_string_to_whole_number() {
-        let result = Fraction::from_str("8/4").unwrap();
-        let expected = 2;
-        assert_eq!(result, expected);
+    fn to_unicode_string_negative() {
+        assert_eq!(Fraction::new(-1, 2).to_unicode_string(), "-½");
+        assert_eq!(Fraction::new(5, -17).to_unicode_string(), "-⁵⁄₁₇");
+        assert_eq!(Fraction::new(-6, 3).to_unicode_string(), "-2");
+        assert_eq!(Fraction::new(i64::MIN, -1).to_unicode_string(), "9223372036854775808");
     }
 
     #[test]
-    fn multiplication() {
-        let f1 = Fraction::new(5, 8);
-        let f2 = Fraction::new(6,12);
-        let result = f1 * f2;
-        let expected = Fraction::new(30, 96);
-        assert_eq!(result, expected);
+    fn to_unicode_string_mixed() {
+        assert_eq!(Fraction::new(11, 4).to_unicode_string_mixed(), "2¾");
+        assert_eq!(Fraction::new(-22, 17).to_unicode_string_mixed(), "-1⁵⁄₁₇");
+        assert_eq!(Fraction::new(3, 4).to_unicode_string_mixed(), "¾");
+        assert_eq!(Fraction::new(-8, 4).to_unicode_string_mixed(), "-2");
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn multiplication_to_whole_number() {
-        let f1 = Fraction::new(9, 3);
-        let f2 = Fraction::new(1,3);
-        let result = f1 * f2;
-        let expected = 1;
-        assert_eq!(result, expected);
+    fn serde_round_trip() {
+        let cases = [(Fraction::new(3, 4), r#""3/4""#), (Fraction::new(-10, -4), r#""10/4""#), (Fraction::from(7), r#""7""#)];
+        for (f, expected) in cases.iter() {
+            let json = serde_json::to_string(f).unwrap();
+            assert_eq!(json, *expected);
+            assert_eq!(serde_json::from_str::<Fraction>(&json).unwrap(), *f);
+        }
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn division() {
-        let f1 = Fraction::new(1, 6);
-        let f2 = Fraction::new(1, 3);
-        let result = f1 / f2;
-        let expected = Fraction::new(1, 2);
-        assert_eq!(result, expected);
+    fn serde_negatives() {
+        assert_eq!(serde_json::to_string(&Fraction::new(3, -4)).unwrap(), r#""-3/4""#);
+        assert_eq!(serde_json::to_string(&Fraction::from(-5)).unwrap(), r#""-5""#);
+        assert_eq!(serde_json::from_str::<Fraction>(r#""-3/4""#).unwrap(), Fraction::new(-3, 4));
+        assert_eq!(serde_json::from_str::<Fraction>("-5").unwrap(), Fraction::from(-5));
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn division_to_whole_number() {
-        let f1 = Fraction::new(1, 2);
-        let f2 = Fraction::new(1, 4);
-        let result = f1 / f2;
-        let expected = 2;
-        assert_eq!(result, expected);
+    fn serde_struct_and_tuple_forms() {
+        let f: Fraction = serde_json::from_str(r#"{"numerator": -3, "denominator": 4}"#).unwrap();
+        assert!(f.is_same_as(&Fraction::new(-3, 4)));
+        let f: Fraction = serde_json::from_str("[6, 8]").unwrap();
+        assert!(f.is_same_as(&Fraction::new(6, 8)));
+        assert!(serde_json::from_str::<Fraction>(r#"{"numerator": 3}"#).is_err());
+        assert!(serde_json::from_str::<Fraction>("[1, 2, 3]").is_err());
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn complex_equation1() {
-        let f1 = Fraction::new(1, 2);
-        let f2 = Fraction::new(1, 4);
-        let result = (f1 + f2 - f2) / f2 * -f1;
-        let expected = -1;
-        assert_eq!(result, expected);
+    fn serde_zero_denominator() {
+        let error = serde_json::from_str::<Fraction>(r#""1/0""#).unwrap_err();
+        assert_eq!(error.to_string(), "could not parse '1/0': the denominator cannot be zero at line 1 column 5");
+        assert!(serde_json::from_str::<Fraction>("[1, 0]").is_err());
+        assert!(serde_json::from_str::<Fraction>(r#"{"numerator": 1, "denominator": 0}"#).is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn accessors_return_stored_values() {
+        let f = Fraction::new(-2, -4);
+        assert_eq!(f.numerator(), -2);
+        assert_eq!(f.denominator(), -4);
+        assert_eq!(f.into_parts(), (-2, -4));
+        assert!(Fraction::from_parts(-2, -4).is_same_as(&f));
+        assert_eq!(f.clone_simplified().into_parts(), (1, 2));
+    }
+}
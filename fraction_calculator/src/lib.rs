@@ -0,0 +1,13 @@
+// Only core and alloc are needed without the `std` feature, tests always have std for the harness
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod fraction;
+pub mod equation;
+pub mod math;
+mod parse;
+#[cfg(feature = "big")]
+pub mod big_fraction;
+#[cfg(feature = "rand")]
+pub mod random;
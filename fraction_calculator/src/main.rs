@@ -1,14 +1,17 @@
 use std::io;
-use fraction::Fraction;
-use equation::Equation;
+use fraction_calculator::equation::Equation;
 use std::io::Write;
 
-mod fraction;
-mod equation;
-
 fn main() {
     println!("Welcome to Aaron's Fraction Calculator!");
-    println!("Numbers include fractions and whole numbers (e.g. -3/4, 2, 17/3)");
+    println!("Numbers include fractions, whole numbers and decimals (e.g. -3/4, 2, 17/3, 0.25)");
+    println!("Repeating decimals put the digits that repeat in parentheses (e.g. 0.(3), 1.2(3))");
+    println!("Percents are divided by 100 (e.g. 50%, 12.5%)");
+    println!("Mixed numbers are written with an underscore (e.g. 1_3/4, -2_1/2)");
+    #[cfg(not(feature = "big"))]
+    println!("Numerators and denominators can be up to ±9223372036854775807 (64-bit integers)");
+    #[cfg(feature = "big")]
+    println!("Numerators and denominators can be arbitrarily large");
     println!("Operators include +, -, *, and /");
     println!("Numbers and operators need to be separated by a space");
     println!("Example: 1/2 - 3/4 * 7");
@@ -39,7 +42,7 @@ fn main() {
             break;
         }
 
-        match Equation::eval(&input) {
+        match Equation::eval(input) {
             Ok(mut result) => {
                 result.simplify();
                 println!(">>> {}", result)
@@ -0,0 +1,183 @@
+use core::convert::TryFrom;
+
+/// Greatest common divisor of the magnitudes, and `gcd(0, 0)` is 0
+///
+/// The result is never negative except for `gcd(i64::MIN, 0)`, `gcd(0, i64::MIN)` and
+/// `gcd(i64::MIN, i64::MIN)`. Their gcd is 2^63, which doesn't fit in an i64 and wraps around to
+/// `i64::MIN` like `i64::MIN.wrapping_abs()`.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    // Binary GCD algorithm (https://en.wikipedia.org/wiki/Binary_GCD_algorithm)
+    binary_gcd(a.unsigned_abs() as u128, b.unsigned_abs() as u128) as i64
+}
+
+/// Least common multiple of the magnitudes, which is 0 if either value is 0
+///
+/// Panics if the result doesn't fit in an i64, use `checked_lcm` to handle it
+pub fn lcm(a: i64, b: i64) -> i64 {
+    checked_lcm(a, b).expect("attempt to calculate the least common multiple with overflow")
+}
+
+/// Returns `None` if the result doesn't fit in an i64
+pub fn checked_lcm(a: i64, b: i64) -> Option<i64> {
+    let (a, b) = (a.unsigned_abs() as u128, b.unsigned_abs() as u128);
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    // Dividing before multiplying keeps the intermediate no larger than the result
+    i64::try_from(a / binary_gcd(a, b) * b).ok()
+}
+
+// GCD of values computed at twice the width of a Fraction's numerator and denominator
+pub(crate) fn gcd_wide(a: i128, b: i128) -> i128 {
+    binary_gcd(a.unsigned_abs(), b.unsigned_abs()) as i128
+}
+
+// Works on magnitudes since i64::MIN and i128::MIN do not fit in their own type once negated
+fn binary_gcd(mut a: u128, mut b: u128) -> u128 {
+    // gcd(0, n) = n and gcd(0, 0) = 0
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+
+    // Factor out the powers of two shared by a and b
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+
+    // a is always odd here, so any remaining factors of two in b are not part of the GCD
+    while b != 0 {
+        b >>= b.trailing_zeros();
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+    }
+    a << shift
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{checked_lcm, gcd, gcd_wide, lcm};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn gcd_with_zero_first() {
+        assert_eq!(gcd(0, 5), 5);
+    }
+
+    #[test]
+    fn gcd_with_zero_last() {
+        assert_eq!(gcd(5, 0), 5);
+    }
+
+    #[test]
+    fn gcd_with_both_zero() {
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn gcd_with_negatives() {
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(-12, -18), 6);
+    }
+
+    #[test]
+    fn gcd_with_powers_of_two() {
+        assert_eq!(gcd(48, 64), 16);
+        assert_eq!(gcd(1 << 30, 1 << 20), 1 << 20);
+    }
+
+    #[test]
+    fn gcd_of_coprimes() {
+        assert_eq!(gcd(1, 1_000_000_000), 1);
+        assert_eq!(gcd(17, 31), 1);
+    }
+
+    #[test]
+    fn gcd_beyond_i32() {
+        assert_eq!(gcd(6_000_000_000, 4_000_000_000), 2_000_000_000);
+        assert_eq!(gcd(i64::MAX, i64::MAX), i64::MAX);
+    }
+
+    #[test]
+    fn gcd_wide_beyond_i64() {
+        assert_eq!(gcd_wide(6 << 80, 4 << 80), 2 << 80);
+        assert_eq!(gcd_wide(-(1 << 100), 0), 1 << 100);
+    }
+
+    #[test]
+    fn gcd_of_i64_min_wraps() {
+        assert_eq!(gcd(i64::MIN, 6), 2);
+        assert_eq!(gcd(i64::MIN, 0), i64::MIN);
+        assert_eq!(gcd(0, i64::MIN), i64::MIN);
+        assert_eq!(gcd(i64::MIN, i64::MIN), i64::MIN);
+        assert_eq!(gcd(i64::MIN, i64::MIN) as u64, 1 << 63);
+        assert_eq!(gcd(i64::MIN, i64::MAX), 1);
+    }
+
+    #[test]
+    fn lcm_of_small_values() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(7, 7), 7);
+        assert_eq!(lcm(1, 9), 9);
+    }
+
+    #[test]
+    fn lcm_with_zero() {
+        assert_eq!(lcm(0, 5), 0);
+        assert_eq!(lcm(5, 0), 0);
+        assert_eq!(lcm(0, 0), 0);
+    }
+
+    #[test]
+    fn lcm_near_overflow() {
+        // The product overflows but the result doesn't
+        assert_eq!(lcm(i64::MAX, i64::MAX), i64::MAX);
+        assert_eq!(lcm(1 << 62, 1 << 61), 1 << 62);
+        assert_eq!(checked_lcm(i64::MIN, 1), None);
+        assert_eq!(checked_lcm(i64::MAX, i64::MAX - 1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn lcm_overflow_panics() {
+        lcm(1 << 62, 3);
+    }
+
+    // The original repeated subtraction GCD, kept as a reference for the binary GCD
+    fn gcd_by_subtraction(a: i64, b: i64) -> i64 {
+        let mut a = a.abs();
+        let mut b = b.abs();
+
+        if a == 0 {
+            return b;
+        }
+        if b == 0 {
+            return a;
+        }
+
+        while a != b {
+            if a > b {
+                a -= b;
+            } else {
+                b -= a;
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn gcd_matches_subtraction() {
+        let mut rng = StdRng::seed_from_u64(377);
+        for _ in 0..10_000 {
+            let a = rng.gen_range(-10_000..=10_000);
+            let b = rng.gen_range(-10_000..=10_000);
+            assert_eq!(gcd(a, b), gcd_by_subtraction(a, b), "gcd({}, {})", a, b);
+        }
+    }
+}
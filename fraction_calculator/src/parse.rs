@@ -0,0 +1,365 @@
+use alloc::string::ToString;
+use crate::fraction::ParseFractionError;
+
+// Parsing shared by `Fraction` and `BigFraction`, which only differ in the integer type the
+// digits are converted to. Everything handed back has already been checked to be digits, with
+// an optional sign where noted, so converting them can only fail by overflowing.
+
+// A mixed number like "-2 1/2" or "1_3/4" split into unsigned digits. The sign is kept apart from
+// the whole part so "-0 1/2" is still negative.
+#[derive(Debug, PartialEq)]
+pub struct MixedNumber<'a> {
+    pub negative: bool,
+    pub whole: &'a str,
+    pub numerator: &'a str,
+    pub denominator: &'a str,
+}
+
+// Returns `None` when the string isn't written as a mixed number at all, and an error when it
+// starts with a whole number and a separator but the fractional part is invalid, e.g. "1 -3/4",
+// "1 3" or the improper "1 5/4"
+pub fn split_mixed(s: &str) -> Result<Option<MixedNumber<'_>>, ParseFractionError> {
+    let separator = match s.find(|c: char| c.is_whitespace() || c == '_') {
+        Some(i) => i,
+        None => return Ok(None),
+    };
+
+    let (negative, whole) = match split_sign(&s[..separator]) {
+        Some(parts) => parts,
+        None => return Ok(None),
+    };
+
+    // The whole and fractional parts are separated by any amount of whitespace or one underscore
+    let rest = &s[separator..];
+    let fraction = match rest.strip_prefix('_') {
+        Some(fraction) => fraction,
+        None => rest.trim_start(),
+    };
+    // Nothing after the separator is trailing garbage after a whole number instead
+    if fraction.is_empty() {
+        return Ok(None);
+    }
+    let invalid = || ParseFractionError::InvalidMixedNumber { text: fraction.to_string() };
+    let (numerator, denominator) = match fraction.find('/') {
+        Some(i) => (&fraction[..i], &fraction[i + 1..]),
+        None => return Err(invalid()),
+    };
+    if !is_digits(numerator) || !is_digits(denominator) {
+        return Err(invalid());
+    }
+    // A zero denominator is left for the caller to report like it would be for "3/0"
+    let zero_denominator = denominator.bytes().all(|b| b == b'0');
+    if !zero_denominator && !is_less(numerator, denominator) {
+        return Err(invalid());
+    }
+
+    Ok(Some(MixedNumber {
+        negative,
+        whole,
+        numerator,
+        denominator,
+    }))
+}
+
+// A decimal like "-1.25" or "0.1(6)" split into unsigned digits, where the digits in parentheses
+// repeat forever. Either the fractional or repeating digits can be empty, but not both.
+#[derive(Debug, PartialEq)]
+pub struct Decimal<'a> {
+    pub negative: bool,
+    pub whole: &'a str,
+    pub fractional: &'a str,
+    pub repeating: &'a str,
+}
+
+// Returns `None` when there's no decimal point and an error when the digits around it are invalid
+pub fn split_decimal(s: &str) -> Result<Option<Decimal<'_>>, ParseFractionError> {
+    let point = match s.find('.') {
+        Some(i) => i,
+        None => return Ok(None),
+    };
+    let (negative, whole) = match split_sign(&s[..point]) {
+        Some(parts) => parts,
+        None => return Err(ParseFractionError::InvalidNumerator { text: s[..point].to_string() }),
+    };
+
+    let rest = &s[point + 1..];
+    let invalid = || ParseFractionError::InvalidDecimal { text: rest.to_string() };
+    let (fractional, repeating) = match rest.find('(') {
+        Some(i) => match rest[i + 1..].strip_suffix(')') {
+            Some(repeating) if is_digits(repeating) => (&rest[..i], repeating),
+            _ => return Err(invalid()),
+        },
+        None => (rest, ""),
+    };
+    if !fractional.is_empty() && !is_digits(fractional) {
+        return Err(invalid());
+    }
+    if fractional.is_empty() && repeating.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(Some(Decimal {
+        negative,
+        whole,
+        fractional,
+        repeating,
+    }))
+}
+
+// Returns the number in front of a trailing percent sign, or `None` when there isn't one. A bare
+// percent sign or one anywhere other than the end is an error.
+pub fn split_percent(s: &str) -> Result<Option<&str>, ParseFractionError> {
+    match s.find('%') {
+        None => Ok(None),
+        Some(0) if s.len() == 1 => Err(ParseFractionError::EmptyInput),
+        Some(0) => Err(ParseFractionError::InvalidNumerator { text: s.to_string() }),
+        Some(i) if i == s.len() - 1 => Ok(Some(&s[..i])),
+        Some(i) => Err(ParseFractionError::TrailingGarbage { text: s[i..].to_string() }),
+    }
+}
+
+// Splits an integer or a plain fraction into its numerator, which can have a sign, and its
+// denominator, which can't. The grammar is exactly `[+-]? digits ('/' digits)?` and anything else
+// is an error naming the part at fault, e.g. "1/2/3" has trailing garbage "/3".
+pub fn split_fraction(s: &str) -> Result<(&str, Option<&str>), ParseFractionError> {
+    if s.is_empty() {
+        return Err(ParseFractionError::EmptyInput);
+    }
+
+    let sign = if s.starts_with(['-', '+']) { 1 } else { 0 };
+    let numerator_end = sign + count_digits(&s[sign..]);
+    let (numerator, rest) = s.split_at(numerator_end);
+    if numerator_end == sign {
+        let text = match s.find('/') {
+            Some(i) => &s[..i],
+            None => s,
+        };
+        return Err(ParseFractionError::InvalidNumerator { text: text.to_string() });
+    }
+
+    let denominator = match rest.strip_prefix('/') {
+        Some(denominator) => denominator,
+        None if rest.is_empty() => return Ok((numerator, None)),
+        // Junk between the numerator and a slash belongs to the numerator, e.g. "3a/4"
+        None => match rest.find('/') {
+            Some(i) => {
+                let text = &s[..numerator_end + i];
+                return Err(ParseFractionError::InvalidNumerator { text: text.to_string() });
+            }
+            None => return Err(ParseFractionError::TrailingGarbage { text: rest.to_string() }),
+        },
+    };
+
+    let digits = count_digits(denominator);
+    if digits == 0 {
+        Err(ParseFractionError::InvalidDenominator { text: denominator.to_string() })
+    } else if digits < denominator.len() {
+        Err(ParseFractionError::TrailingGarbage { text: denominator[digits..].to_string() })
+    } else {
+        Ok((numerator, Some(denominator)))
+    }
+}
+
+fn count_digits(s: &str) -> usize {
+    s.bytes().take_while(|b| b.is_ascii_digit()).count()
+}
+
+// Splits an optional leading sign off a run of digits
+fn split_sign(s: &str) -> Option<(bool, &str)> {
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if is_digits(digits) {
+        Some((negative, digits))
+    } else {
+        None
+    }
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+// Compares two strings of digits by value without converting them, so it works for any length
+fn is_less(a: &str, b: &str) -> bool {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    (a.len(), a) < (b.len(), b)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fraction::ParseFractionError;
+    use crate::parse::{split_decimal, split_fraction, split_mixed, split_percent, Decimal, MixedNumber};
+
+    fn mixed<'a>(negative: bool, whole: &'a str, numerator: &'a str, denominator: &'a str) -> MixedNumber<'a> {
+        MixedNumber { negative, whole, numerator, denominator }
+    }
+
+    fn invalid_mixed(text: &str) -> ParseFractionError {
+        ParseFractionError::InvalidMixedNumber { text: text.to_string() }
+    }
+
+    #[test]
+    fn split_mixed_separators() {
+        assert_eq!(split_mixed("1 3/4"), Ok(Some(mixed(false, "1", "3", "4"))));
+        assert_eq!(split_mixed("1   3/4"), Ok(Some(mixed(false, "1", "3", "4"))));
+        assert_eq!(split_mixed("1_3/4"), Ok(Some(mixed(false, "1", "3", "4"))));
+        assert_eq!(split_mixed("-2\t1/2"), Ok(Some(mixed(true, "2", "1", "2"))));
+    }
+
+    #[test]
+    fn split_mixed_keeps_sign_of_zero() {
+        assert_eq!(split_mixed("-0 1/2"), Ok(Some(mixed(true, "0", "1", "2"))));
+    }
+
+    #[test]
+    fn split_mixed_not_mixed() {
+        assert_eq!(split_mixed("3/4"), Ok(None));
+        assert_eq!(split_mixed("-12"), Ok(None));
+        assert_eq!(split_mixed("3/4 "), Ok(None));
+        assert_eq!(split_mixed("12 "), Ok(None));
+        assert_eq!(split_mixed("12_"), Ok(None));
+    }
+
+    #[test]
+    fn split_mixed_invalid() {
+        assert_eq!(split_mixed("1 -3/4"), Err(invalid_mixed("-3/4")));
+        assert_eq!(split_mixed("1 3"), Err(invalid_mixed("3")));
+        assert_eq!(split_mixed("1__3/4"), Err(invalid_mixed("_3/4")));
+        assert_eq!(split_mixed("1_ 3/4"), Err(invalid_mixed(" 3/4")));
+        assert_eq!(split_mixed("1 3/4 5"), Err(invalid_mixed("3/4 5")));
+    }
+
+    #[test]
+    fn split_mixed_improper() {
+        assert_eq!(split_mixed("1 5/4"), Err(invalid_mixed("5/4")));
+        assert_eq!(split_mixed("1 4/4"), Err(invalid_mixed("4/4")));
+        assert_eq!(split_mixed("1 004/4"), Err(invalid_mixed("004/4")));
+        assert_eq!(split_mixed("1 10/9"), Err(invalid_mixed("10/9")));
+        assert_eq!(split_mixed("1 03/04"), Ok(Some(mixed(false, "1", "03", "04"))));
+        assert_eq!(split_mixed("1 0/4"), Ok(Some(mixed(false, "1", "0", "4"))));
+        assert_eq!(split_mixed("1 9/10"), Ok(Some(mixed(false, "1", "9", "10"))));
+        assert_eq!(split_mixed("1 3/0"), Ok(Some(mixed(false, "1", "3", "0"))));
+    }
+
+    fn decimal<'a>(negative: bool, whole: &'a str, fractional: &'a str, repeating: &'a str) -> Decimal<'a> {
+        Decimal { negative, whole, fractional, repeating }
+    }
+
+    #[test]
+    fn split_decimal_parts() {
+        assert_eq!(split_decimal("0.75"), Ok(Some(decimal(false, "0", "75", ""))));
+        assert_eq!(split_decimal("-2.25"), Ok(Some(decimal(true, "2", "25", ""))));
+        assert_eq!(split_decimal("0.(142857)"), Ok(Some(decimal(false, "0", "", "142857"))));
+        assert_eq!(split_decimal("1.2(3)"), Ok(Some(decimal(false, "1", "2", "3"))));
+    }
+
+    #[test]
+    fn split_decimal_not_decimal() {
+        assert_eq!(split_decimal("3/4"), Ok(None));
+        assert_eq!(split_decimal("12"), Ok(None));
+    }
+
+    #[test]
+    fn split_decimal_invalid_digits() {
+        let invalid = [
+            ("1.", ""),
+            ("1.2.3", "2.3"),
+            ("1.()", "()"),
+            ("1.(3", "(3"),
+            ("1.(3)4", "(3)4"),
+            ("1.2(x)", "2(x)"),
+            ("1.5/2", "5/2"),
+            ("1.-5", "-5"),
+        ];
+        for (s, text) in invalid.iter() {
+            let expected = ParseFractionError::InvalidDecimal { text: text.to_string() };
+            assert_eq!(split_decimal(s), Err(expected), "{}", s);
+        }
+    }
+
+    #[test]
+    fn split_decimal_invalid_whole() {
+        for (s, text) in [(".5", ""), ("--1.5", "--1"), ("1/2.5", "1/2")].iter() {
+            let expected = ParseFractionError::InvalidNumerator { text: text.to_string() };
+            assert_eq!(split_decimal(s), Err(expected), "{}", s);
+        }
+    }
+
+    #[test]
+    fn split_percent_number() {
+        assert_eq!(split_percent("75%"), Ok(Some("75")));
+        assert_eq!(split_percent("-12.5%"), Ok(Some("-12.5")));
+        assert_eq!(split_percent("75"), Ok(None));
+    }
+
+    #[test]
+    fn split_percent_misplaced() {
+        assert_eq!(split_percent("%"), Err(ParseFractionError::EmptyInput));
+        let expected = ParseFractionError::InvalidNumerator { text: "%75".to_string() };
+        assert_eq!(split_percent("%75"), Err(expected));
+        for (s, text) in [("7%5", "%5"), ("75%%", "%%"), ("75%/2", "%/2")].iter() {
+            let expected = ParseFractionError::TrailingGarbage { text: text.to_string() };
+            assert_eq!(split_percent(s), Err(expected), "{}", s);
+        }
+    }
+
+    #[test]
+    fn split_fraction_parts() {
+        assert_eq!(split_fraction("-3/4"), Ok(("-3", Some("4"))));
+        assert_eq!(split_fraction("+3/4"), Ok(("+3", Some("4"))));
+        assert_eq!(split_fraction("+12"), Ok(("+12", None)));
+        assert_eq!(split_fraction("007/010"), Ok(("007", Some("010"))));
+    }
+
+    #[test]
+    fn split_fraction_invalid() {
+        let invalid_numerator = |text: &str| ParseFractionError::InvalidNumerator { text: text.to_string() };
+        let invalid_denominator = |text: &str| ParseFractionError::InvalidDenominator { text: text.to_string() };
+        let trailing = |text: &str| ParseFractionError::TrailingGarbage { text: text.to_string() };
+
+        assert_eq!(split_fraction(""), Err(ParseFractionError::EmptyInput));
+        assert_eq!(split_fraction("3a/4"), Err(invalid_numerator("3a")));
+        assert_eq!(split_fraction("/4"), Err(invalid_numerator("")));
+        assert_eq!(split_fraction("abc"), Err(invalid_numerator("abc")));
+        assert_eq!(split_fraction("-"), Err(invalid_numerator("-")));
+        assert_eq!(split_fraction("3/"), Err(invalid_denominator("")));
+        assert_eq!(split_fraction("3/x4"), Err(invalid_denominator("x4")));
+        assert_eq!(split_fraction("3/4x"), Err(trailing("x")));
+        assert_eq!(split_fraction("3/4/5"), Err(trailing("/5")));
+        assert_eq!(split_fraction("12abc"), Err(trailing("abc")));
+    }
+
+    #[test]
+    fn split_fraction_near_misses() {
+        let invalid_numerator = |text: &str| ParseFractionError::InvalidNumerator { text: text.to_string() };
+        let invalid_denominator = |text: &str| ParseFractionError::InvalidDenominator { text: text.to_string() };
+        let trailing = |text: &str| ParseFractionError::TrailingGarbage { text: text.to_string() };
+
+        let near_misses = [
+            ("1/2/3", trailing("/3")),
+            ("1//2", invalid_denominator("/2")),
+            ("1 /2", invalid_numerator("1 ")),
+            ("1/ 2", invalid_denominator(" 2")),
+            (" 1/2", invalid_numerator(" 1")),
+            ("1/2 ", trailing(" ")),
+            ("3/-4", invalid_denominator("-4")),
+            ("3/+4", invalid_denominator("+4")),
+            ("--3/4", invalid_numerator("--3")),
+            ("+-3", invalid_numerator("+-3")),
+            ("-", invalid_numerator("-")),
+            ("+", invalid_numerator("+")),
+            ("/", invalid_numerator("")),
+            ("3-", trailing("-")),
+            ("1e5", trailing("e5")),
+            ("0x10", trailing("x10")),
+            ("١/٢", invalid_numerator("١")),
+        ];
+        for (s, expected) in near_misses.iter() {
+            assert_eq!(split_fraction(s).as_ref(), Err(expected), "{:?}", s);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use core::ops::RangeInclusive;
+use rand::distributions::{Distribution, Uniform};
+use rand::Rng;
+use crate::fraction::Fraction;
+
+/// Random fractions with the numerator and denominator each picked uniformly from a range,
+/// enabled with the `rand` feature
+///
+/// A zero denominator is never picked, the sample is drawn again instead. The values aren't
+/// spread evenly, e.g. fractions near zero come up more often than fractions near the ends of the
+/// range, which suits property tests that want a mix of simple and awkward fractions.
+#[derive(Debug, Clone)]
+pub struct UniformFraction {
+    numerators: Uniform<i64>,
+    denominators: Uniform<i64>,
+    reduced: bool,
+}
+
+impl UniformFraction {
+    /// Panics if either range is empty or zero is the only denominator in its range
+    pub fn new(numerators: RangeInclusive<i64>, denominators: RangeInclusive<i64>) -> Self {
+        assert!(!numerators.is_empty() && !denominators.is_empty(), "attempt to sample fractions from an empty range");
+        assert!(denominators != (0..=0), "attempt to sample fractions with only a zero denominator");
+
+        Self {
+            numerators: Uniform::from(numerators),
+            denominators: Uniform::from(denominators),
+            reduced: false,
+        }
+    }
+
+    /// Puts every sample in lowest terms with a positive denominator
+    pub fn reduced(mut self) -> Self {
+        self.reduced = true;
+        self
+    }
+}
+
+impl Distribution<Fraction> for UniformFraction {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fraction {
+        loop {
+            let denominator = self.denominators.sample(rng);
+            if denominator == 0 {
+                continue;
+            }
+
+            let f = Fraction::new(self.numerators.sample(rng), denominator);
+            if !self.reduced {
+                return f;
+            }
+            // i64::MIN / -1 has no reduced form that fits, so it's drawn again like a zero denominator
+            if let Some(f) = f.checked_simplified() {
+                return f;
+            }
+        }
+    }
+}
+
+impl Fraction {
+    /// A random fraction in lowest terms within `range`, with a denominator of at most
+    /// `max_denominator`. The denominator is picked uniformly first and then a numerator that
+    /// fits in the range over it. Panics if the range is empty, `max_denominator` is less than
+    /// one, no fraction in the range has a small enough denominator, or on overflow.
+    pub fn random_in<R>(range: RangeInclusive<Fraction>, max_denominator: i64, rng: &mut R) -> Fraction
+    where
+        R: Rng + ?Sized,
+    {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "attempt to pick a fraction from an empty range");
+        // If any fraction in the range has a small enough denominator, then the closest one to the
+        // middle of the range is in it too, so this also means the loop below always finishes
+        let closest = ((lo + hi) / Fraction::from(2)).limit_denominator(max_denominator);
+        assert!(
+            lo <= closest && closest <= hi,
+            "attempt to pick a fraction from a range without any small enough denominators"
+        );
+
+        loop {
+            let denominator = rng.gen_range(1..=max_denominator);
+            let first = lo.ceil_to(denominator).numerator();
+            let last = hi.floor_to(denominator).numerator();
+            if first <= last {
+                return Fraction::new(rng.gen_range(first..=last), denominator).clone_simplified();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fraction::Fraction;
+    use crate::random::UniformFraction;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn pairs(seed: u64) -> impl Iterator<Item = (Fraction, Fraction)> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let distribution = UniformFraction::new(-1_000_000..=1_000_000, -1_000_000..=1_000_000);
+        (0..10_000).map(move |_| (distribution.sample(&mut rng), distribution.sample(&mut rng)))
+    }
+
+    #[test]
+    fn never_samples_a_zero_denominator() {
+        let mut rng = StdRng::seed_from_u64(422);
+        let distribution = UniformFraction::new(-5..=5, -1..=1);
+        for f in distribution.sample_iter(&mut rng).take(1000) {
+            assert!(f.denominator() == 1 || f.denominator() == -1);
+        }
+    }
+
+    #[test]
+    fn reduced_samples() {
+        let mut rng = StdRng::seed_from_u64(422);
+        let distribution = UniformFraction::new(-100..=100, -100..=100).reduced();
+        for f in distribution.sample_iter(&mut rng).take(1000) {
+            assert!(f.is_same_as(&f.clone_simplified()), "{}", f);
+        }
+    }
+
+    #[test]
+    fn reduced_samples_skip_i64_min_over_minus_one() {
+        let mut rng = StdRng::seed_from_u64(422);
+        let distribution = UniformFraction::new(i64::MIN..=i64::MIN, -1..=1).reduced();
+        for f in distribution.sample_iter(&mut rng).take(100) {
+            assert!(f.is_same_as(&Fraction::from_int(i64::MIN)));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "only a zero denominator")]
+    fn zero_denominator_range_panics() {
+        UniformFraction::new(1..=2, 0..=0);
+    }
+
+    #[test]
+    fn random_in_range() {
+        let mut rng = StdRng::seed_from_u64(422);
+        let (lo, hi) = (Fraction::new(-3, 7), Fraction::new(2, 5));
+        for _ in 0..1000 {
+            let f = Fraction::random_in(lo..=hi, 16, &mut rng);
+            assert!(lo <= f && f <= hi, "{}", f);
+            assert!((1..=16).contains(&f.denominator()), "{}", f);
+            assert!(f.is_same_as(&f.clone_simplified()), "{}", f);
+        }
+    }
+
+    #[test]
+    fn random_in_range_with_a_single_value() {
+        let mut rng = StdRng::seed_from_u64(422);
+        let f = Fraction::random_in(Fraction::new(1, 3)..=Fraction::new(2, 6), 4, &mut rng);
+        assert!(f.is_same_as(&Fraction::new(1, 3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "small enough denominators")]
+    fn random_in_range_without_small_denominators_panics() {
+        let mut rng = StdRng::seed_from_u64(422);
+        Fraction::random_in(Fraction::new(1, 3)..=Fraction::new(1, 3), 2, &mut rng);
+    }
+
+    #[test]
+    fn addition_and_multiplication_commute() {
+        for (a, b) in pairs(423) {
+            assert_eq!(a.checked_add(b), b.checked_add(a), "{} and {}", a, b);
+            assert_eq!(a.checked_mul(b), b.checked_mul(a), "{} and {}", a, b);
+        }
+    }
+
+    #[test]
+    fn subtracting_itself_is_zero() {
+        for (a, _) in pairs(424) {
+            assert_eq!(a.checked_sub(a), Some(Fraction::ZERO), "{}", a);
+        }
+    }
+
+    #[test]
+    fn dividing_then_multiplying_is_identity() {
+        for (a, b) in pairs(425) {
+            if b.is_zero() {
+                assert_eq!(a.checked_div(b), None);
+                continue;
+            }
+            let result = a.checked_div(b).and_then(|quotient| quotient.checked_mul(b));
+            assert_eq!(result, Some(a), "{} and {}", a, b);
+        }
+    }
+}